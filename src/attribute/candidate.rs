@@ -0,0 +1,314 @@
+use std::fmt;
+
+use nom::{
+    branch::alt,
+    bytes::complete::{tag, take_till1},
+    character::complete::{space1, u16, u32},
+    combinator::{map, opt},
+    error::ParseError,
+    multi::many0,
+    sequence::{preceded, separated_pair, terminated},
+    IResult, Parser,
+};
+
+use crate::session_desription::Rebind;
+use crate::session_desription::{parse_connection_address, ConnectionAddress};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CandidateType {
+    Host,
+    Srflx,
+    Prflx,
+    Relay,
+}
+
+impl fmt::Display for CandidateType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CandidateType::Host => write!(f, "host"),
+            CandidateType::Srflx => write!(f, "srflx"),
+            CandidateType::Prflx => write!(f, "prflx"),
+            CandidateType::Relay => write!(f, "relay"),
+        }
+    }
+}
+
+/// The value of an `a=candidate:` attribute, describing one ICE candidate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Candidate<'a> {
+    foundation: &'a str,
+    component: u32,
+    transport: &'a str,
+    priority: u32,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    address: ConnectionAddress<'a>,
+    port: u16,
+    candidate_type: CandidateType,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    related_address: Option<ConnectionAddress<'a>>,
+    related_port: Option<u16>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    extensions: Vec<(&'a str, &'a str)>,
+}
+
+impl<'a> Candidate<'a> {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        foundation: &'a str,
+        component: u32,
+        transport: &'a str,
+        priority: u32,
+        address: ConnectionAddress<'a>,
+        port: u16,
+        candidate_type: CandidateType,
+        related_address: Option<ConnectionAddress<'a>>,
+        related_port: Option<u16>,
+    ) -> Self {
+        Self {
+            foundation,
+            component,
+            transport,
+            priority,
+            address,
+            port,
+            candidate_type,
+            related_address,
+            related_port,
+            extensions: Vec::new(),
+        }
+    }
+
+    pub fn foundation(&self) -> &'a str {
+        self.foundation
+    }
+
+    /// Returns the component id, `1` for RTP and `2` for RTCP.
+    pub fn component(&self) -> u32 {
+        self.component
+    }
+
+    /// Returns the transport protocol, e.g. `UDP`.
+    pub fn transport(&self) -> &'a str {
+        self.transport
+    }
+
+    pub fn priority(&self) -> u32 {
+        self.priority
+    }
+
+    pub fn address(&self) -> &ConnectionAddress<'a> {
+        &self.address
+    }
+
+    pub fn port(&self) -> u16 {
+        self.port
+    }
+
+    pub fn candidate_type(&self) -> &CandidateType {
+        &self.candidate_type
+    }
+
+    /// Returns the related address (`raddr`) of a reflexive or relayed candidate.
+    pub fn related_address(&self) -> Option<&ConnectionAddress<'a>> {
+        self.related_address.as_ref()
+    }
+
+    /// Returns the related port (`rport`) of a reflexive or relayed candidate.
+    pub fn related_port(&self) -> Option<u16> {
+        self.related_port
+    }
+
+    /// Returns the extension attributes following the candidate, e.g. `generation 0`.
+    pub fn extensions(&self) -> &[(&'a str, &'a str)] {
+        &self.extensions
+    }
+
+    pub(crate) fn set_transport(&mut self, transport: &'a str) {
+        self.transport = transport;
+    }
+}
+
+impl Rebind for Candidate<'_> {
+    type Output<'b> = Candidate<'b>;
+
+    fn rebind<'b>(&self, strings: &mut dyn FnMut(&str) -> &'b str) -> Candidate<'b> {
+        Candidate {
+            foundation: strings(self.foundation),
+            component: self.component,
+            transport: strings(self.transport),
+            priority: self.priority,
+            address: self.address.rebind(strings),
+            port: self.port,
+            candidate_type: self.candidate_type.clone(),
+            related_address: self.related_address.rebind(strings),
+            related_port: self.related_port,
+            extensions: self.extensions.rebind(strings),
+        }
+    }
+}
+
+impl fmt::Display for Candidate<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {} {} {} {} typ {}",
+            self.foundation,
+            self.component,
+            self.transport,
+            self.priority,
+            self.address,
+            self.port,
+            self.candidate_type
+        )?;
+        if let Some(related_address) = &self.related_address {
+            write!(f, " raddr {}", related_address)?;
+        }
+        if let Some(related_port) = self.related_port {
+            write!(f, " rport {}", related_port)?;
+        }
+        for (name, value) in &self.extensions {
+            write!(f, " {} {}", name, value)?;
+        }
+        Ok(())
+    }
+}
+
+fn parse_token<'i, E: ParseError<&'i str>>(input: &'i str) -> IResult<&'i str, &'i str, E> {
+    take_till1(|c: char| c.is_whitespace()).parse(input)
+}
+
+fn parse_candidate_type<'i, E: ParseError<&'i str>>(
+    input: &'i str,
+) -> IResult<&'i str, CandidateType, E> {
+    alt((
+        map(tag("host"), |_| CandidateType::Host),
+        map(tag("srflx"), |_| CandidateType::Srflx),
+        map(tag("prflx"), |_| CandidateType::Prflx),
+        map(tag("relay"), |_| CandidateType::Relay),
+    ))
+    .parse(input)
+}
+
+/// a=candidate:<foundation> <component-id> <transport> <priority> <connection-address> <port>
+///     typ <cand-type> [raddr <connection-address>] [rport <port>] *(<extension-att-name> <extension-att-value>)
+/// a=candidate:1 1 UDP 2130706431 192.168.1.1 54321 typ host
+/// see https://tools.ietf.org/html/rfc8839#section-5.1
+pub fn parse_candidate<'i, E: ParseError<&'i str>>(
+    input: &'i str,
+) -> IResult<&'i str, Candidate<'i>, E> {
+    let (tail, foundation) = terminated(parse_token, space1).parse(input)?;
+    let (tail, component) = terminated(u32, space1).parse(tail)?;
+    let (tail, transport) = terminated(parse_token, space1).parse(tail)?;
+    let (tail, priority) = terminated(u32, space1).parse(tail)?;
+    let (tail, address) = terminated(parse_connection_address, space1).parse(tail)?;
+    let (tail, port) = terminated(u16, space1).parse(tail)?;
+    let (tail, candidate_type) = preceded(tag("typ "), parse_candidate_type).parse(tail)?;
+    let (tail, related_address) =
+        opt(preceded(tag(" raddr "), parse_connection_address)).parse(tail)?;
+    let (tail, related_port) = opt(preceded(tag(" rport "), u16)).parse(tail)?;
+    let (tail, extensions) = many0(preceded(
+        space1,
+        separated_pair(parse_token, space1, parse_token),
+    ))
+    .parse(tail)?;
+
+    let mut candidate = Candidate::new(
+        foundation,
+        component,
+        transport,
+        priority,
+        address,
+        port,
+        candidate_type,
+        related_address,
+        related_port,
+    );
+    candidate.extensions = extensions;
+    Ok((tail, candidate))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+    use super::*;
+
+    #[test]
+    fn test_parse_host_candidate() {
+        let input = "1 1 UDP 2130706431 192.168.1.1 54321 typ host";
+        let (tail, value) = parse_candidate::<()>(input).unwrap();
+        assert_eq!(tail, "");
+        assert_eq!(value.foundation, "1");
+        assert_eq!(value.component, 1);
+        assert_eq!(value.transport, "UDP");
+        assert_eq!(value.priority, 2130706431);
+        assert_eq!(
+            value.address,
+            ConnectionAddress::Ip(IpAddr::V4(Ipv4Addr::new(192, 168, 1, 1)))
+        );
+        assert_eq!(value.port, 54321);
+        assert_eq!(value.candidate_type, CandidateType::Host);
+        assert_eq!(value.related_address, None);
+        assert_eq!(value.related_port, None);
+        assert_eq!(value.to_string(), input);
+    }
+
+    #[test]
+    fn test_parse_srflx_candidate() {
+        let input = "842163049 1 udp 1677729535 203.0.113.7 61665 typ srflx raddr 10.0.1.1 rport 8998 generation 0 network-cost 999";
+        let (tail, value) = parse_candidate::<()>(input).unwrap();
+        assert_eq!(tail, "");
+        assert_eq!(value.candidate_type, CandidateType::Srflx);
+        assert_eq!(
+            value.related_address,
+            Some(ConnectionAddress::Ip(IpAddr::V4(Ipv4Addr::new(
+                10, 0, 1, 1
+            ))))
+        );
+        assert_eq!(value.related_port, Some(8998));
+        assert_eq!(
+            value.extensions,
+            vec![("generation", "0"), ("network-cost", "999")]
+        );
+        assert_eq!(value.to_string(), input);
+    }
+
+    #[test]
+    fn test_parse_ipv6_candidate() {
+        let input = "2 1 udp 2122262783 2001:db8::1 54322 typ host";
+        let (tail, value) = parse_candidate::<()>(input).unwrap();
+        assert_eq!(tail, "");
+        assert_eq!(
+            value.address,
+            ConnectionAddress::Ip(IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1)))
+        );
+        assert_eq!(value.to_string(), input);
+    }
+
+    #[test]
+    fn test_parse_mdns_candidate() {
+        let input = "1 1 UDP 2130706431 abcd-1234.local 54321 typ host";
+        let (tail, value) = parse_candidate::<()>(input).unwrap();
+        assert_eq!(tail, "");
+        assert_eq!(value.address, ConnectionAddress::Fqdn("abcd-1234.local"));
+        assert_eq!(value.to_string(), input);
+
+        let input = "3 1 udp 1686052607 203.0.113.7 61665 typ srflx raddr 0c7f0a4e-5d3b-4e8c-9a1f-2b6d8e4c7a90.local rport 61665";
+        let (_, value) = parse_candidate::<()>(input).unwrap();
+        assert_eq!(
+            value.related_address,
+            Some(ConnectionAddress::Fqdn(
+                "0c7f0a4e-5d3b-4e8c-9a1f-2b6d8e4c7a90.local"
+            ))
+        );
+        assert_eq!(value.to_string(), input);
+    }
+
+    #[test]
+    fn test_parse_candidate_unknown_type() {
+        let input = "1 1 UDP 2130706431 192.168.1.1 54321 typ bogus";
+        assert!(parse_candidate::<()>(input).is_err());
+    }
+}
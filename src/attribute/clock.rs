@@ -0,0 +1,206 @@
+use std::fmt;
+
+use nom::{
+    bytes::complete::take_till1,
+    character::complete::{char, space1},
+    combinator::{opt, rest},
+    error::ParseError,
+    sequence::preceded,
+    IResult, Parser,
+};
+
+use super::parse_token;
+use crate::session_desription::Rebind;
+
+/// The value of an `a=ts-refclk:` attribute, the reference clock RTP timestamps are derived
+/// from, e.g. `ptp=IEEE1588-2008:39-A7-94-FF-FE-07-CB-D0:37` or `local`.
+///
+/// The clock value is kept as written, since its syntax depends on the clock source and
+/// vendors extend it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TsRefClk<'a> {
+    source: &'a str,
+    value: Option<&'a str>,
+}
+
+impl<'a> TsRefClk<'a> {
+    pub fn new(source: &'a str, value: Option<&'a str>) -> Self {
+        Self { source, value }
+    }
+
+    /// Returns the clock source, e.g. `ntp`, `ptp`, `gps`, `localmac` or `local`.
+    pub fn source(&self) -> &'a str {
+        self.source
+    }
+
+    /// Returns the part after the `=`, e.g. `IEEE1588-2008:39-A7-94-FF-FE-07-CB-D0:37`.
+    pub fn value(&self) -> Option<&'a str> {
+        self.value
+    }
+
+    /// Returns the `:`-separated fields of the value, e.g. the PTP version, grandmaster
+    /// identity and domain of a `ptp` clock.
+    pub fn fields(&self) -> impl Iterator<Item = &'a str> {
+        self.value.into_iter().flat_map(|value| value.split(':'))
+    }
+}
+
+impl Rebind for TsRefClk<'_> {
+    type Output<'b> = TsRefClk<'b>;
+
+    fn rebind<'b>(&self, strings: &mut dyn FnMut(&str) -> &'b str) -> TsRefClk<'b> {
+        TsRefClk {
+            source: strings(self.source),
+            value: self.value.rebind(strings),
+        }
+    }
+}
+
+impl fmt::Display for TsRefClk<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.source)?;
+        if let Some(value) = self.value {
+            write!(f, "={}", value)?;
+        }
+        Ok(())
+    }
+}
+
+/// The value of an `a=mediaclk:` attribute, how RTP timestamps relate to the reference
+/// clock, e.g. `direct=0` or `sender`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MediaClk<'a> {
+    source: &'a str,
+    value: Option<&'a str>,
+    parameters: Option<&'a str>,
+}
+
+impl<'a> MediaClk<'a> {
+    pub fn new(source: &'a str, value: Option<&'a str>, parameters: Option<&'a str>) -> Self {
+        Self {
+            source,
+            value,
+            parameters,
+        }
+    }
+
+    /// Returns the media clock source, e.g. `direct`, `sender` or `IEEE2059-2`.
+    pub fn source(&self) -> &'a str {
+        self.source
+    }
+
+    /// Returns the part after the `=`, e.g. the RTP timestamp offset `0` of `direct=0`.
+    pub fn value(&self) -> Option<&'a str> {
+        self.value
+    }
+
+    /// Returns the raw parameters following the source, e.g. `rate=90000/1`.
+    pub fn parameters(&self) -> Option<&'a str> {
+        self.parameters
+    }
+
+    /// Returns the media clock rate (`rate=`) as written, e.g. `90000/1`, if given.
+    pub fn rate(&self) -> Option<&'a str> {
+        self.parameters?
+            .split_whitespace()
+            .find_map(|parameter| parameter.strip_prefix("rate="))
+    }
+}
+
+impl Rebind for MediaClk<'_> {
+    type Output<'b> = MediaClk<'b>;
+
+    fn rebind<'b>(&self, strings: &mut dyn FnMut(&str) -> &'b str) -> MediaClk<'b> {
+        MediaClk {
+            source: strings(self.source),
+            value: self.value.rebind(strings),
+            parameters: self.parameters.rebind(strings),
+        }
+    }
+}
+
+impl fmt::Display for MediaClk<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.source)?;
+        if let Some(value) = self.value {
+            write!(f, "={}", value)?;
+        }
+        if let Some(parameters) = self.parameters {
+            write!(f, " {}", parameters)?;
+        }
+        Ok(())
+    }
+}
+
+fn parse_clock_source<'i, E: ParseError<&'i str>>(input: &'i str) -> IResult<&'i str, &'i str, E> {
+    take_till1(|c: char| c == '=' || c.is_whitespace()).parse(input)
+}
+
+/// a=ts-refclk:<clksrc>[=<value>]
+/// a=ts-refclk:ptp=IEEE1588-2008:39-A7-94-FF-FE-07-CB-D0:37
+/// see https://tools.ietf.org/html/rfc7273#section-4.8
+pub fn parse_ts_refclk<'i, E: ParseError<&'i str>>(
+    input: &'i str,
+) -> IResult<&'i str, TsRefClk<'i>, E> {
+    let (tail, source) = parse_clock_source(input)?;
+    let (tail, value) = opt(preceded(char('='), rest)).parse(tail)?;
+    Ok((tail, TsRefClk::new(source, value)))
+}
+
+/// a=mediaclk:<mediaclock>[=<value>] [<parameters>]
+/// a=mediaclk:direct=0 rate=90000/1
+/// see https://tools.ietf.org/html/rfc7273#section-5.3
+pub fn parse_mediaclk<'i, E: ParseError<&'i str>>(
+    input: &'i str,
+) -> IResult<&'i str, MediaClk<'i>, E> {
+    let (tail, source) = parse_clock_source(input)?;
+    let (tail, value) = opt(preceded(char('='), parse_token)).parse(tail)?;
+    let (tail, parameters) = opt(preceded(space1, rest)).parse(tail)?;
+    Ok((tail, MediaClk::new(source, value, parameters)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_ts_refclk() {
+        let input = "ptp=IEEE1588-2008:39-A7-94-FF-FE-07-CB-D0:37";
+        let (tail, value) = parse_ts_refclk::<()>(input).unwrap();
+        assert_eq!(tail, "");
+        assert_eq!(value.source(), "ptp");
+        assert_eq!(
+            value.fields().collect::<Vec<_>>(),
+            ["IEEE1588-2008", "39-A7-94-FF-FE-07-CB-D0", "37"]
+        );
+        assert_eq!(value.to_string(), input);
+
+        for input in ["local", "ntp=/traceable/", "localmac=7C-E9-D3-1B-9A-AF"] {
+            let (tail, value) = parse_ts_refclk::<()>(input).unwrap();
+            assert_eq!(tail, "");
+            assert_eq!(value.to_string(), input);
+        }
+        assert_eq!(parse_ts_refclk::<()>("local").unwrap().1.value(), None);
+    }
+
+    #[test]
+    fn test_parse_mediaclk() {
+        let (tail, value) = parse_mediaclk::<()>("direct=0").unwrap();
+        assert_eq!(tail, "");
+        assert_eq!(value, MediaClk::new("direct", Some("0"), None));
+        assert_eq!(value.rate(), None);
+
+        let input = "direct=963214424 rate=1000/1001";
+        let (tail, value) = parse_mediaclk::<()>(input).unwrap();
+        assert_eq!(tail, "");
+        assert_eq!(value.value(), Some("963214424"));
+        assert_eq!(value.rate(), Some("1000/1001"));
+        assert_eq!(value.to_string(), input);
+
+        let (_, value) = parse_mediaclk::<()>("sender").unwrap();
+        assert_eq!(value.source(), "sender");
+        assert!(parse_mediaclk::<()>("=0").is_err());
+    }
+}
@@ -0,0 +1,84 @@
+use std::fmt;
+
+/// A media direction attribute: `a=sendrecv`, `a=sendonly`, `a=recvonly` or `a=inactive`.
+///
+/// see https://tools.ietf.org/html/rfc8866#section-6.7
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Direction {
+    /// The endpoint sends and receives media.
+    SendRecv,
+    /// The endpoint only sends media.
+    SendOnly,
+    /// The endpoint only receives media.
+    RecvOnly,
+    /// The endpoint neither sends nor receives media, e.g. while on hold.
+    Inactive,
+}
+
+impl Direction {
+    /// Returns the direction for a property attribute name, or `None` if `name` is not a
+    /// direction attribute.
+    pub(crate) fn from_name(name: &str) -> Option<Direction> {
+        match name {
+            "sendrecv" => Some(Direction::SendRecv),
+            "sendonly" => Some(Direction::SendOnly),
+            "recvonly" => Some(Direction::RecvOnly),
+            "inactive" => Some(Direction::Inactive),
+            _ => None,
+        }
+    }
+
+    /// Returns the direction as seen from the other endpoint, e.g. `sendonly` for the
+    /// `recvonly` of an answer that only receives what the offerer sends.
+    pub fn reverse(&self) -> Direction {
+        match self {
+            Direction::SendOnly => Direction::RecvOnly,
+            Direction::RecvOnly => Direction::SendOnly,
+            direction => *direction,
+        }
+    }
+
+    /// Returns the attribute name of the direction, e.g. `sendrecv`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Direction::SendRecv => "sendrecv",
+            Direction::SendOnly => "sendonly",
+            Direction::RecvOnly => "recvonly",
+            Direction::Inactive => "inactive",
+        }
+    }
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_direction_from_name() {
+        for (name, expected) in [
+            ("sendrecv", Direction::SendRecv),
+            ("sendonly", Direction::SendOnly),
+            ("recvonly", Direction::RecvOnly),
+            ("inactive", Direction::Inactive),
+        ] {
+            assert_eq!(Direction::from_name(name), Some(expected));
+            assert_eq!(expected.to_string(), name);
+        }
+        assert_eq!(Direction::from_name("rtcp-mux"), None);
+    }
+
+    #[test]
+    fn test_direction_reverse() {
+        assert_eq!(Direction::SendOnly.reverse(), Direction::RecvOnly);
+        assert_eq!(Direction::RecvOnly.reverse(), Direction::SendOnly);
+        assert_eq!(Direction::SendRecv.reverse(), Direction::SendRecv);
+        assert_eq!(Direction::Inactive.reverse(), Direction::Inactive);
+    }
+}
@@ -0,0 +1,145 @@
+use std::fmt;
+
+use nom::{
+    bytes::complete::take_till1,
+    character::complete::{alpha1, char, space1, u8},
+    combinator::{map_opt, opt, rest, verify},
+    error::ParseError,
+    sequence::preceded,
+    IResult, Parser,
+};
+
+use super::Direction;
+use crate::session_desription::Rebind;
+
+/// The value of an `a=extmap:` attribute, mapping an RTP header extension to a local id.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ExtMap<'a> {
+    id: u8,
+    direction: Option<Direction>,
+    uri: &'a str,
+    extension_attributes: Option<&'a str>,
+}
+
+impl<'a> ExtMap<'a> {
+    pub fn new(
+        id: u8,
+        direction: Option<Direction>,
+        uri: &'a str,
+        extension_attributes: Option<&'a str>,
+    ) -> Self {
+        Self {
+            id,
+            direction,
+            uri,
+            extension_attributes,
+        }
+    }
+
+    /// Returns the local identifier of the extension, 1-255.
+    pub fn id(&self) -> u8 {
+        self.id
+    }
+
+    /// Returns `true` if the id only fits the two-byte header form, i.e. it is 15 or above.
+    pub fn is_two_byte(&self) -> bool {
+        self.id >= 15
+    }
+
+    pub fn direction(&self) -> Option<Direction> {
+        self.direction
+    }
+
+    /// Returns the URI naming the extension, e.g. `urn:ietf:params:rtp-hdrext:toffset`.
+    pub fn uri(&self) -> &'a str {
+        self.uri
+    }
+
+    /// Returns the extension specific attributes following the URI, if any.
+    pub fn extension_attributes(&self) -> Option<&'a str> {
+        self.extension_attributes
+    }
+}
+
+impl Rebind for ExtMap<'_> {
+    type Output<'b> = ExtMap<'b>;
+
+    fn rebind<'b>(&self, strings: &mut dyn FnMut(&str) -> &'b str) -> ExtMap<'b> {
+        ExtMap {
+            id: self.id,
+            direction: self.direction,
+            uri: strings(self.uri),
+            extension_attributes: self.extension_attributes.rebind(strings),
+        }
+    }
+}
+
+impl fmt::Display for ExtMap<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.id)?;
+        if let Some(direction) = self.direction {
+            write!(f, "/{}", direction)?;
+        }
+        write!(f, " {}", self.uri)?;
+        if let Some(extension_attributes) = self.extension_attributes {
+            write!(f, " {}", extension_attributes)?;
+        }
+        Ok(())
+    }
+}
+
+/// a=extmap:<value>["/"<direction>] <URI> <extensionattributes>
+/// a=extmap:1/sendonly urn:ietf:params:rtp-hdrext:toffset
+/// see https://tools.ietf.org/html/rfc8285#section-8
+pub fn parse_extmap<'i, E: ParseError<&'i str>>(input: &'i str) -> IResult<&'i str, ExtMap<'i>, E> {
+    // 0 is reserved for padding in both the one-byte and the two-byte header form
+    let (tail, id) = verify(u8, |id: &u8| *id != 0).parse(input)?;
+    let (tail, direction) =
+        opt(preceded(char('/'), map_opt(alpha1, Direction::from_name))).parse(tail)?;
+    let (tail, uri) = preceded(space1, take_till1(|c: char| c.is_whitespace())).parse(tail)?;
+    let (tail, extension_attributes) = opt(preceded(space1, rest)).parse(tail)?;
+    Ok((tail, ExtMap::new(id, direction, uri, extension_attributes)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_extmap() {
+        let input = "1/sendonly urn:ietf:params:rtp-hdrext:toffset";
+        let (tail, value) = parse_extmap::<()>(input).unwrap();
+        assert_eq!(tail, "");
+        assert_eq!(value.id, 1);
+        assert_eq!(value.direction, Some(Direction::SendOnly));
+        assert_eq!(value.uri, "urn:ietf:params:rtp-hdrext:toffset");
+        assert_eq!(value.extension_attributes, None);
+        assert!(!value.is_two_byte());
+        assert_eq!(value.to_string(), input);
+    }
+
+    #[test]
+    fn test_parse_extmap_with_extension_attributes() {
+        let input = "15 urn:ietf:params:rtp-hdrext:ssrc-audio-level vad=on";
+        let (_, value) = parse_extmap::<()>(input).unwrap();
+        assert_eq!(
+            value,
+            ExtMap::new(
+                15,
+                None,
+                "urn:ietf:params:rtp-hdrext:ssrc-audio-level",
+                Some("vad=on")
+            )
+        );
+        assert!(value.is_two_byte());
+        assert_eq!(value.to_string(), input);
+    }
+
+    #[test]
+    fn test_parse_extmap_invalid_id() {
+        assert!(parse_extmap::<()>("0 urn:ietf:params:rtp-hdrext:toffset").is_err());
+        assert!(parse_extmap::<()>("256 urn:ietf:params:rtp-hdrext:toffset").is_err());
+        assert!(parse_extmap::<()>("1/sometimes urn:ietf:params:rtp-hdrext:toffset").is_err());
+    }
+}
@@ -0,0 +1,102 @@
+use std::fmt;
+
+use nom::{
+    bytes::complete::take_till1,
+    character::complete::space1,
+    combinator::{rest, verify},
+    error::ParseError,
+    sequence::separated_pair,
+    IResult, Parser,
+};
+
+use crate::session_desription::Rebind;
+
+/// The value of an `a=fingerprint:` attribute, the fingerprint of a DTLS certificate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Fingerprint<'a> {
+    hash_function: &'a str,
+    fingerprint: &'a str,
+}
+
+impl<'a> Fingerprint<'a> {
+    pub fn new(hash_function: &'a str, fingerprint: &'a str) -> Self {
+        Self {
+            hash_function,
+            fingerprint,
+        }
+    }
+
+    /// Returns the hash function, e.g. `sha-256`.
+    pub fn hash_function(&self) -> &'a str {
+        self.hash_function
+    }
+
+    /// Returns the fingerprint as colon-separated uppercase or lowercase hex pairs.
+    pub fn fingerprint(&self) -> &'a str {
+        self.fingerprint
+    }
+}
+
+impl Rebind for Fingerprint<'_> {
+    type Output<'b> = Fingerprint<'b>;
+
+    fn rebind<'b>(&self, strings: &mut dyn FnMut(&str) -> &'b str) -> Fingerprint<'b> {
+        Fingerprint {
+            hash_function: strings(self.hash_function),
+            fingerprint: strings(self.fingerprint),
+        }
+    }
+}
+
+impl fmt::Display for Fingerprint<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.hash_function, self.fingerprint)
+    }
+}
+
+fn is_hex_pairs(s: &str) -> bool {
+    s.split(':')
+        .all(|pair| pair.len() == 2 && pair.chars().all(|c| c.is_ascii_hexdigit()))
+}
+
+/// a=fingerprint:<hash-func> <fingerprint>
+/// a=fingerprint:sha-256 4A:AD:B9:B1:3F:82:18:3B:54:02:12:DF:3E:5D:49:6B:19:E5:7C:AB
+/// see https://tools.ietf.org/html/rfc8122#section-5
+pub fn parse_fingerprint<'i, E: ParseError<&'i str>>(
+    input: &'i str,
+) -> IResult<&'i str, Fingerprint<'i>, E> {
+    let (tail, (hash_function, fingerprint)) = separated_pair(
+        take_till1(|c: char| c.is_whitespace()),
+        space1,
+        verify(rest, is_hex_pairs),
+    )
+    .parse(input)?;
+    Ok((tail, Fingerprint::new(hash_function, fingerprint)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_fingerprint() {
+        let input = "sha-256 4A:AD:B9:B1:3F:82:18:3B:54:02:12:DF:3E:5D:49:6B:19:E5:7C:AB";
+        let (tail, value) = parse_fingerprint::<()>(input).unwrap();
+        assert_eq!(tail, "");
+        assert_eq!(value.hash_function, "sha-256");
+        assert_eq!(
+            value.fingerprint,
+            "4A:AD:B9:B1:3F:82:18:3B:54:02:12:DF:3E:5D:49:6B:19:E5:7C:AB"
+        );
+        assert_eq!(value.to_string(), input);
+    }
+
+    #[test]
+    fn test_parse_fingerprint_malformed() {
+        assert!(parse_fingerprint::<()>("sha-256 4A:AD:B").is_err());
+        assert!(parse_fingerprint::<()>("sha-256 4A:XY").is_err());
+        assert!(parse_fingerprint::<()>("sha-256 4AAD").is_err());
+        assert!(parse_fingerprint::<()>("sha-256").is_err());
+    }
+}
@@ -0,0 +1,149 @@
+use std::{collections::HashMap, fmt};
+
+use nom::{
+    character::complete::{space1, u8},
+    combinator::rest,
+    error::ParseError,
+    sequence::separated_pair,
+    IResult, Parser,
+};
+
+use crate::session_desription::Rebind;
+
+/// The value of an `a=fmtp:` attribute, carrying the format parameters of a payload type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Fmtp<'a> {
+    payload_type: u8,
+    parameters: &'a str,
+}
+
+impl<'a> Fmtp<'a> {
+    pub fn new(payload_type: u8, parameters: &'a str) -> Self {
+        Self {
+            payload_type,
+            parameters,
+        }
+    }
+
+    pub fn payload_type(&self) -> u8 {
+        self.payload_type
+    }
+
+    /// Returns the raw format parameters, e.g. `minptime=10;useinbandfec=1`.
+    pub fn parameters(&self) -> &'a str {
+        self.parameters
+    }
+
+    /// Returns the `key=value` pairs of the format parameters.
+    ///
+    /// Parameters given without a value, e.g. the bare `0-15` of
+    /// `a=fmtp:101 0-15`, map to an empty string.
+    pub fn parameter_map(&self) -> HashMap<&'a str, &'a str> {
+        self.parameter_pairs().collect()
+    }
+
+    /// Returns the value of the parameter `key`, e.g. `42e01f` for `profile-level-id`.
+    ///
+    /// Parameter names are compared case-insensitively, as media type parameters are. A
+    /// parameter given without a value yields an empty string.
+    pub fn get(&self, key: &str) -> Option<&'a str> {
+        self.parameter_pairs()
+            .find(|(name, _)| name.eq_ignore_ascii_case(key))
+            .map(|(_, value)| value)
+    }
+
+    /// Returns the value of the parameter `key` as an integer, e.g. `96` for `apt`, or `None`
+    /// if it is absent or not a number.
+    pub fn get_u32(&self, key: &str) -> Option<u32> {
+        self.get(key)?.parse().ok()
+    }
+
+    /// Returns the `key=value` pairs of the format parameters, in the order they appeared.
+    pub fn parameter_pairs(&self) -> impl Iterator<Item = (&'a str, &'a str)> {
+        self.parameters
+            .split(';')
+            .map(str::trim)
+            .filter(|parameter| !parameter.is_empty())
+            .map(|parameter| match parameter.split_once('=') {
+                Some((key, value)) => (key.trim(), value.trim()),
+                None => (parameter, ""),
+            })
+    }
+}
+
+impl Rebind for Fmtp<'_> {
+    type Output<'b> = Fmtp<'b>;
+
+    fn rebind<'b>(&self, strings: &mut dyn FnMut(&str) -> &'b str) -> Fmtp<'b> {
+        Fmtp {
+            payload_type: self.payload_type,
+            parameters: strings(self.parameters),
+        }
+    }
+}
+
+impl fmt::Display for Fmtp<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.payload_type, self.parameters)
+    }
+}
+
+/// a=fmtp:<format> <format specific parameters>
+/// a=fmtp:96 minptime=10;useinbandfec=1
+/// see https://tools.ietf.org/html/rfc8866#section-6.15
+pub fn parse_fmtp<'i, E: ParseError<&'i str>>(input: &'i str) -> IResult<&'i str, Fmtp<'i>, E> {
+    let (tail, (payload_type, parameters)) = separated_pair(u8, space1, rest).parse(input)?;
+    Ok((tail, Fmtp::new(payload_type, parameters)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_fmtp() {
+        let (tail, value) = parse_fmtp::<()>("96 minptime=10;useinbandfec=1").unwrap();
+        assert_eq!(tail, "");
+        assert_eq!(value.payload_type, 96);
+        assert_eq!(value.parameters, "minptime=10;useinbandfec=1");
+        assert_eq!(value.to_string(), "96 minptime=10;useinbandfec=1");
+    }
+
+    #[test]
+    fn test_fmtp_parameter_map() {
+        let (_, value) = parse_fmtp::<()>("96 minptime=10; useinbandfec=1").unwrap();
+        let parameters = value.parameter_map();
+        assert_eq!(parameters.len(), 2);
+        assert_eq!(parameters["minptime"], "10");
+        assert_eq!(parameters["useinbandfec"], "1");
+    }
+
+    #[test]
+    fn test_fmtp_parameter_map_bare_value() {
+        let (_, value) = parse_fmtp::<()>("101 0-15").unwrap();
+        assert_eq!(value.parameter_map()["0-15"], "");
+    }
+
+    #[test]
+    fn test_fmtp_get() {
+        let (_, value) =
+            parse_fmtp::<()>("97 profile-level-id=42e01f;level-asymmetry-allowed=1;apt=96")
+                .unwrap();
+        assert_eq!(value.get("profile-level-id"), Some("42e01f"));
+        assert_eq!(value.get("Profile-Level-Id"), Some("42e01f"));
+        assert_eq!(value.get_u32("apt"), Some(96));
+        assert_eq!(value.get_u32("profile-level-id"), None);
+        assert_eq!(value.get("packetization-mode"), None);
+        let keys: Vec<_> = value.parameter_pairs().map(|(key, _)| key).collect();
+        assert_eq!(keys, ["profile-level-id", "level-asymmetry-allowed", "apt"]);
+
+        let (_, value) = parse_fmtp::<()>("101 0-15").unwrap();
+        assert_eq!(value.get("0-15"), Some(""));
+    }
+
+    #[test]
+    fn test_parse_fmtp_invalid_payload_type() {
+        assert!(parse_fmtp::<()>("opus minptime=10").is_err());
+    }
+}
@@ -0,0 +1,88 @@
+use std::fmt;
+
+use nom::{
+    character::complete::{char, digit1},
+    combinator::{opt, recognize},
+    error::ParseError,
+    sequence::{pair, preceded},
+    IResult, Parser,
+};
+
+use crate::session_desription::Rebind;
+
+/// The value of an `a=framerate:` attribute, the maximum video frame rate in frames per
+/// second, e.g. `30` or `29.97`.
+///
+/// The decimal is kept as written so that it serializes back unchanged.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Framerate<'a> {
+    framerate: &'a str,
+}
+
+impl<'a> Framerate<'a> {
+    /// Creates a frame rate from its decimal form, `<digits>[.<digits>]`.
+    pub fn new(framerate: &'a str) -> Self {
+        Self { framerate }
+    }
+
+    /// Returns the frame rate as written, e.g. `29.97`.
+    pub fn as_str(&self) -> &'a str {
+        self.framerate
+    }
+
+    /// Returns the frame rate in frames per second, `0.0` if it is not a decimal.
+    pub fn value(&self) -> f64 {
+        self.framerate.parse().unwrap_or_default()
+    }
+}
+
+impl Rebind for Framerate<'_> {
+    type Output<'b> = Framerate<'b>;
+
+    fn rebind<'b>(&self, strings: &mut dyn FnMut(&str) -> &'b str) -> Framerate<'b> {
+        Framerate {
+            framerate: strings(self.framerate),
+        }
+    }
+}
+
+impl fmt::Display for Framerate<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.framerate)
+    }
+}
+
+/// a=framerate:<frame rate>
+/// a=framerate:29.97
+/// see https://tools.ietf.org/html/rfc8866#section-6.8
+pub fn parse_framerate<'i, E: ParseError<&'i str>>(
+    input: &'i str,
+) -> IResult<&'i str, Framerate<'i>, E> {
+    let (tail, framerate) =
+        recognize(pair(digit1, opt(preceded(char('.'), digit1)))).parse(input)?;
+    Ok((tail, Framerate::new(framerate)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_framerate() {
+        for (input, expected) in [("30", 30.0), ("29.97", 29.97), ("0.5", 0.5)] {
+            let (tail, value) = parse_framerate::<()>(input).unwrap();
+            assert_eq!(tail, "");
+            assert_eq!(value.value(), expected);
+            assert_eq!(value.to_string(), input);
+        }
+    }
+
+    #[test]
+    fn test_parse_malformed_framerate() {
+        assert!(parse_framerate::<()>(".5").is_err());
+        assert!(parse_framerate::<()>("fast").is_err());
+        let (tail, _) = parse_framerate::<()>("29.").unwrap();
+        assert_eq!(tail, ".");
+    }
+}
@@ -0,0 +1,98 @@
+use std::fmt;
+
+use nom::{
+    character::complete::space1, error::ParseError, multi::many0, sequence::preceded, IResult,
+    Parser,
+};
+
+use super::parse_token;
+use crate::session_desription::Rebind;
+
+/// The value of an `a=group:` attribute, grouping media sections by their `a=mid:` tag.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Group<'a> {
+    semantics: &'a str,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    identifiers: Vec<&'a str>,
+}
+
+impl<'a> Group<'a> {
+    pub fn new(semantics: &'a str, identifiers: Vec<&'a str>) -> Self {
+        Self {
+            semantics,
+            identifiers,
+        }
+    }
+
+    /// Returns the grouping semantics as it appeared, e.g. `BUNDLE`, `LS` or `FID`; semantics
+    /// this crate does not know about are kept too.
+    pub fn semantics(&self) -> &'a str {
+        self.semantics
+    }
+
+    /// Returns the identification tags (`a=mid:`) of the grouped media sections.
+    pub fn identifiers(&self) -> &[&'a str] {
+        &self.identifiers
+    }
+}
+
+impl Rebind for Group<'_> {
+    type Output<'b> = Group<'b>;
+
+    fn rebind<'b>(&self, strings: &mut dyn FnMut(&str) -> &'b str) -> Group<'b> {
+        Group {
+            semantics: strings(self.semantics),
+            identifiers: self.identifiers.rebind(strings),
+        }
+    }
+}
+
+impl fmt::Display for Group<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.semantics)?;
+        for identifier in &self.identifiers {
+            write!(f, " {}", identifier)?;
+        }
+        Ok(())
+    }
+}
+
+/// a=group:<semantics> *(<identification-tag>)
+/// a=group:BUNDLE audio video
+/// see https://tools.ietf.org/html/rfc5888#section-5
+pub fn parse_group<'i, E: ParseError<&'i str>>(input: &'i str) -> IResult<&'i str, Group<'i>, E> {
+    let (tail, semantics) = parse_token(input)?;
+    let (tail, identifiers) = many0(preceded(space1, parse_token)).parse(tail)?;
+    Ok((tail, Group::new(semantics, identifiers)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_group() {
+        let (tail, value) = parse_group::<()>("BUNDLE audio video").unwrap();
+        assert_eq!(tail, "");
+        assert_eq!(value.semantics, "BUNDLE");
+        assert_eq!(value.identifiers, vec!["audio", "video"]);
+        assert_eq!(value.to_string(), "BUNDLE audio video");
+    }
+
+    #[test]
+    fn test_parse_group_other_semantics() {
+        for (input, semantics) in [
+            ("LS 1 2", "LS"),
+            ("FID 1 2", "FID"),
+            ("SRF 1 2", "SRF"),
+            ("X-VENDOR 1 2", "X-VENDOR"),
+        ] {
+            let (tail, value) = parse_group::<()>(input).unwrap();
+            assert_eq!(tail, "");
+            assert_eq!(value.semantics(), semantics);
+            assert_eq!(value.identifiers(), &["1", "2"]);
+            assert_eq!(value.to_string(), input);
+        }
+    }
+}
@@ -0,0 +1,265 @@
+use std::fmt;
+
+use nom::{
+    branch::alt,
+    bytes::complete::{tag, take_till, take_till1},
+    character::complete::{char, space1, u8},
+    combinator::{map, opt, recognize},
+    error::ParseError,
+    multi::separated_list1,
+    sequence::{delimited, preceded, separated_pair},
+    IResult, Parser,
+};
+
+use crate::session_desription::Rebind;
+
+/// The value of an `a=imageattr:` attribute, the image resolutions a payload type may be
+/// sent and received with.
+///
+/// For each direction, an empty list of sets stands for `*`, i.e. any resolution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ImageAttr<'a> {
+    payload_type: Option<u8>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    send: Option<Vec<ImageAttrSet<'a>>>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    recv: Option<Vec<ImageAttrSet<'a>>>,
+}
+
+impl<'a> ImageAttr<'a> {
+    pub fn new(
+        payload_type: Option<u8>,
+        send: Option<Vec<ImageAttrSet<'a>>>,
+        recv: Option<Vec<ImageAttrSet<'a>>>,
+    ) -> Self {
+        Self {
+            payload_type,
+            send,
+            recv,
+        }
+    }
+
+    /// Returns the payload type the attribute applies to, `None` for all of them (`*`).
+    pub fn payload_type(&self) -> Option<u8> {
+        self.payload_type
+    }
+
+    /// Returns the sets of resolutions accepted for sending, if the direction is given.
+    pub fn send(&self) -> Option<&[ImageAttrSet<'a>]> {
+        self.send.as_deref()
+    }
+
+    /// Returns the sets of resolutions accepted for receiving, if the direction is given.
+    pub fn recv(&self) -> Option<&[ImageAttrSet<'a>]> {
+        self.recv.as_deref()
+    }
+}
+
+impl Rebind for ImageAttr<'_> {
+    type Output<'b> = ImageAttr<'b>;
+
+    fn rebind<'b>(&self, strings: &mut dyn FnMut(&str) -> &'b str) -> ImageAttr<'b> {
+        ImageAttr {
+            payload_type: self.payload_type,
+            send: self.send.rebind(strings),
+            recv: self.recv.rebind(strings),
+        }
+    }
+}
+
+impl fmt::Display for ImageAttr<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.payload_type {
+            Some(payload_type) => write!(f, "{}", payload_type)?,
+            None => write!(f, "*")?,
+        }
+        for (direction, sets) in [("send", &self.send), ("recv", &self.recv)] {
+            match sets.as_deref() {
+                None => {}
+                Some([]) => write!(f, " {} *", direction)?,
+                Some(sets) => {
+                    write!(f, " {}", direction)?;
+                    for set in sets {
+                        write!(f, " {}", set)?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A bracketed set of an `a=imageattr:` attribute, e.g. `[x=[640:1280],y=720,q=0.5]`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ImageAttrSet<'a> {
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    parameters: Vec<(&'a str, &'a str)>,
+}
+
+impl<'a> ImageAttrSet<'a> {
+    pub fn new(parameters: Vec<(&'a str, &'a str)>) -> Self {
+        Self { parameters }
+    }
+
+    /// Returns the `key=value` pairs of the set, in the order they appeared. Ranges are
+    /// kept as written, e.g. `[640:16:1280]` or `[320,640]`.
+    pub fn parameters(&self) -> &[(&'a str, &'a str)] {
+        &self.parameters
+    }
+
+    /// Returns the value of the parameter `key`, e.g. `x`, `y`, `sar`, `par` or `q`.
+    pub fn get(&self, key: &str) -> Option<&'a str> {
+        self.parameters
+            .iter()
+            .find(|(name, _)| *name == key)
+            .map(|(_, value)| *value)
+    }
+
+    /// Returns the smallest and largest width of the set in pixels.
+    pub fn width_bounds(&self) -> Option<(u32, u32)> {
+        bounds(self.get("x")?)
+    }
+
+    /// Returns the smallest and largest height of the set in pixels.
+    pub fn height_bounds(&self) -> Option<(u32, u32)> {
+        bounds(self.get("y")?)
+    }
+}
+
+impl Rebind for ImageAttrSet<'_> {
+    type Output<'b> = ImageAttrSet<'b>;
+
+    fn rebind<'b>(&self, strings: &mut dyn FnMut(&str) -> &'b str) -> ImageAttrSet<'b> {
+        ImageAttrSet {
+            parameters: self.parameters.rebind(strings),
+        }
+    }
+}
+
+impl fmt::Display for ImageAttrSet<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[")?;
+        for (i, (key, value)) in self.parameters.iter().enumerate() {
+            if i > 0 {
+                write!(f, ",")?;
+            }
+            write!(f, "{}={}", key, value)?;
+        }
+        write!(f, "]")
+    }
+}
+
+/// Returns the bounds of a value, a range `[<min>:[<step>:]<max>]` or a list `[<v>,<v>...]`.
+fn bounds(value: &str) -> Option<(u32, u32)> {
+    let values = match value.strip_prefix('[').and_then(|v| v.strip_suffix(']')) {
+        Some(range) if range.contains(':') => {
+            let mut values = range.split(':');
+            vec![values.next()?, values.next_back()?]
+        }
+        Some(list) => list.split(',').collect(),
+        None => vec![value],
+    };
+    let values = values
+        .into_iter()
+        .map(str::parse)
+        .collect::<Result<Vec<u32>, _>>()
+        .ok()?;
+    Some((*values.iter().min()?, *values.iter().max()?))
+}
+
+fn parse_set<'i, E: ParseError<&'i str>>(input: &'i str) -> IResult<&'i str, ImageAttrSet<'i>, E> {
+    let key = take_till1(|c: char| c == '=' || c == ',' || c == ']' || c.is_whitespace());
+    let value = alt((
+        recognize(delimited(char('['), take_till(|c| c == ']'), char(']'))),
+        take_till1(|c: char| c == ',' || c == ']' || c.is_whitespace()),
+    ));
+    let parameters = separated_list1(char(','), separated_pair(key, char('='), value));
+    map(
+        delimited(char('['), parameters, char(']')),
+        ImageAttrSet::new,
+    )
+    .parse(input)
+}
+
+fn parse_sets<'i, E: ParseError<&'i str>>(
+    input: &'i str,
+) -> IResult<&'i str, Vec<ImageAttrSet<'i>>, E> {
+    alt((
+        map(char('*'), |_| Vec::new()),
+        separated_list1(space1, parse_set),
+    ))
+    .parse(input)
+}
+
+/// a=imageattr:<payload type> send <sets> recv <sets>
+/// a=imageattr:97 send [x=800,y=640,sar=1.1,q=0.6] [x=480,y=320] recv [x=330,y=250]
+/// see https://tools.ietf.org/html/rfc6236#section-3.1
+pub fn parse_imageattr<'i, E: ParseError<&'i str>>(
+    input: &'i str,
+) -> IResult<&'i str, ImageAttr<'i>, E> {
+    let (tail, payload_type) = alt((map(u8, Some), map(char('*'), |_| None))).parse(input)?;
+    let (tail, first) = preceded(space1, alt((tag("send"), tag("recv")))).parse(tail)?;
+    let (tail, first_sets) = preceded(space1, parse_sets).parse(tail)?;
+    let other = if first == "send" { "recv" } else { "send" };
+    let (tail, other_sets) =
+        opt(preceded(delimited(space1, tag(other), space1), parse_sets)).parse(tail)?;
+    let imageattr = if first == "send" {
+        ImageAttr::new(payload_type, Some(first_sets), other_sets)
+    } else {
+        ImageAttr::new(payload_type, other_sets, Some(first_sets))
+    };
+    Ok((tail, imageattr))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_imageattr() {
+        let input = "96 send [x=1280,y=720] recv [x=640,y=480]";
+        let (tail, value) = parse_imageattr::<()>(input).unwrap();
+        assert_eq!(tail, "");
+        assert_eq!(value.payload_type(), Some(96));
+        let send = value.send().unwrap();
+        assert_eq!(send, [ImageAttrSet::new(vec![("x", "1280"), ("y", "720")])]);
+        assert_eq!(send[0].width_bounds(), Some((1280, 1280)));
+        assert_eq!(value.recv().unwrap()[0].get("y"), Some("480"));
+        assert_eq!(value.to_string(), input);
+    }
+
+    #[test]
+    fn test_parse_imageattr_ranges() {
+        let input = "97 send [x=[480:16:800],y=[320:16:640],par=[1.2-1.3],q=0.6] [x=[176:8:208],y=[144:8:176],par=[1.2-1.3]] recv *";
+        let (tail, value) = parse_imageattr::<()>(input).unwrap();
+        assert_eq!(tail, "");
+        let send = value.send().unwrap();
+        assert_eq!(send.len(), 2);
+        assert_eq!(send[0].width_bounds(), Some((480, 800)));
+        assert_eq!(send[0].height_bounds(), Some((320, 640)));
+        assert_eq!(send[0].get("q"), Some("0.6"));
+        assert_eq!(send[1].width_bounds(), Some((176, 208)));
+        assert_eq!(value.recv(), Some(&[][..]));
+        assert_eq!(value.to_string(), input);
+    }
+
+    #[test]
+    fn test_parse_imageattr_wildcard_and_lists() {
+        let (tail, value) = parse_imageattr::<()>("* recv [x=[320,640,1280],y=360]").unwrap();
+        assert_eq!(tail, "");
+        assert_eq!(value.payload_type(), None);
+        assert_eq!(value.send(), None);
+        let recv = value.recv().unwrap();
+        assert_eq!(recv[0].width_bounds(), Some((320, 1280)));
+        assert_eq!(value.to_string(), "* recv [x=[320,640,1280],y=360]");
+    }
+
+    #[test]
+    fn test_parse_malformed_imageattr() {
+        assert!(parse_imageattr::<()>("96 both [x=1,y=1]").is_err());
+        assert!(parse_imageattr::<()>("96 send x=1,y=1").is_err());
+        assert!(parse_imageattr::<()>("96 send [x=1,y=1").is_err());
+    }
+}
@@ -0,0 +1,242 @@
+use std::{fmt, ops::Deref};
+
+use super::Attribute;
+use crate::session_desription::{write_line, Rebind};
+
+/// The `a=` lines of a session or media section, in the order they appeared.
+///
+/// The lines of a `<type>` this crate does not know about are kept here too, along with the
+/// number of attributes preceding each of them, so that they are serialized back among the
+/// `a=` lines where they appeared.
+///
+/// Dereferences to the typed [`Attribute`]s. When parsed with
+/// [`ParseOptions::preserve_attribute_lines`](crate::ParseOptions::preserve_attribute_lines),
+/// every attribute and unknown line also keeps the line it was parsed from, and is serialized
+/// back to that exact line, line ending included, until it is replaced. This keeps the
+/// untouched lines byte-identical even where the typed attribute would serialize differently.
+///
+/// Two lists are equal if their typed attributes and unknown lines are, whichever lines they
+/// were parsed from.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AttributeList<'a> {
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    attributes: Vec<Attribute<'a>>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    lines: Vec<Option<&'a str>>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    unknown_lines: Vec<(char, &'a str)>,
+    unknown_line_positions: Vec<usize>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    unknown_line_texts: Vec<Option<&'a str>>,
+}
+
+impl<'a> AttributeList<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the attributes along with the line each one was parsed from, without its line
+    /// ending, if it was kept.
+    pub fn lines(&self) -> impl Iterator<Item = (&Attribute<'a>, Option<&'a str>)> {
+        let lines = self
+            .lines
+            .iter()
+            .map(|line| line.map(|line| line.trim_end_matches('\n').trim_end_matches('\r')));
+        self.attributes.iter().zip(lines)
+    }
+
+    /// Appends `attribute`, which will be serialized from its typed value.
+    pub fn push(&mut self, attribute: Attribute<'a>) {
+        self.attributes.push(attribute);
+        self.lines.push(None);
+    }
+
+    /// Replaces the attribute at `index`, returning the previous one. The new attribute is
+    /// serialized from its typed value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn replace(&mut self, index: usize, attribute: Attribute<'a>) -> Attribute<'a> {
+        self.lines[index] = None;
+        std::mem::replace(&mut self.attributes[index], attribute)
+    }
+
+    /// Removes the attribute at `index` and returns it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn remove(&mut self, index: usize) -> Attribute<'a> {
+        for position in &mut self.unknown_line_positions {
+            if *position > index {
+                *position -= 1;
+            }
+        }
+        self.lines.remove(index);
+        self.attributes.remove(index)
+    }
+
+    /// Keeps only the attributes for which `f` returns `true`, in their order.
+    pub fn retain(&mut self, mut f: impl FnMut(&Attribute<'a>) -> bool) {
+        let kept: Vec<bool> = self.attributes.iter().map(&mut f).collect();
+        for position in &mut self.unknown_line_positions {
+            *position = kept[..*position].iter().filter(|&&kept| kept).count();
+        }
+        let mut kept = kept.into_iter();
+        let attributes = std::mem::take(&mut self.attributes);
+        let lines = std::mem::take(&mut self.lines);
+        (self.attributes, self.lines) = attributes
+            .into_iter()
+            .zip(lines)
+            .filter(|_| kept.next().unwrap_or_default())
+            .unzip();
+    }
+
+    /// Returns the lines with a `<type>` this crate does not know about, as
+    /// `(<type>, <value>)` pairs in the order they appeared.
+    pub fn unknown_lines(&self) -> &[(char, &'a str)] {
+        &self.unknown_lines
+    }
+
+    pub(crate) fn push_line(&mut self, attribute: Attribute<'a>, line: Option<&'a str>) {
+        self.attributes.push(attribute);
+        self.lines.push(line);
+    }
+
+    /// Appends a line of an unknown `<type>`, after the attributes pushed so far.
+    pub(crate) fn push_unknown_line(
+        &mut self,
+        line_type: char,
+        value: &'a str,
+        line: Option<&'a str>,
+    ) {
+        self.unknown_lines.push((line_type, value));
+        self.unknown_line_positions.push(self.attributes.len());
+        self.unknown_line_texts.push(line);
+    }
+}
+
+impl<'a> Deref for AttributeList<'a> {
+    type Target = [Attribute<'a>];
+
+    fn deref(&self) -> &Self::Target {
+        &self.attributes
+    }
+}
+
+impl PartialEq for AttributeList<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.attributes == other.attributes && self.unknown_lines == other.unknown_lines
+    }
+}
+
+impl Eq for AttributeList<'_> {}
+
+impl<'a> From<Vec<Attribute<'a>>> for AttributeList<'a> {
+    fn from(attributes: Vec<Attribute<'a>>) -> Self {
+        let lines = vec![None; attributes.len()];
+        Self {
+            attributes,
+            lines,
+            ..Self::default()
+        }
+    }
+}
+
+impl Rebind for AttributeList<'_> {
+    type Output<'b> = AttributeList<'b>;
+
+    fn rebind<'b>(&self, strings: &mut dyn FnMut(&str) -> &'b str) -> AttributeList<'b> {
+        AttributeList {
+            attributes: self.attributes.rebind(strings),
+            lines: self.lines.rebind(strings),
+            unknown_lines: self
+                .unknown_lines
+                .iter()
+                .map(|(line_type, value)| (*line_type, strings(value)))
+                .collect(),
+            unknown_line_positions: self.unknown_line_positions.clone(),
+            unknown_line_texts: self.unknown_line_texts.rebind(strings),
+        }
+    }
+}
+
+impl fmt::Display for AttributeList<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let write_unknown_line = |f: &mut fmt::Formatter<'_>, index: usize| {
+            let (line_type, value) = self.unknown_lines[index];
+            match self.unknown_line_texts.get(index).copied().flatten() {
+                Some(line) => write_line(f, line),
+                None => write!(f, "{}={}\r\n", line_type, value),
+            }
+        };
+        let mut unknown_lines = self.unknown_line_positions.iter().enumerate().peekable();
+        for (index, (attribute, line)) in self.attributes.iter().zip(&self.lines).enumerate() {
+            while let Some((unknown, _)) =
+                unknown_lines.next_if(|(_, position)| **position <= index)
+            {
+                write_unknown_line(f, unknown)?;
+            }
+            match line {
+                Some(line) => write_line(f, line)?,
+                None => write!(f, "{}", attribute)?,
+            }
+        }
+        for (unknown, _) in unknown_lines {
+            write_unknown_line(f, unknown)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_attribute_list() {
+        let mut list = AttributeList::new();
+        list.push_line(Attribute::Ptime(20), Some("a=ptime:020"));
+        list.push(Attribute::Property("rtcp-mux"));
+        assert_eq!(list.len(), 2);
+        assert_eq!(list[0], Attribute::Ptime(20));
+        assert_eq!(list.to_string(), "a=ptime:020\r\na=rtcp-mux\r\n");
+        assert_eq!(
+            list,
+            AttributeList::from(vec![Attribute::Ptime(20), Attribute::Property("rtcp-mux")])
+        );
+
+        assert_eq!(list.replace(0, Attribute::Ptime(30)), Attribute::Ptime(20));
+        assert_eq!(list.to_string(), "a=ptime:30\r\na=rtcp-mux\r\n");
+        list.push(Attribute::MediaId("0"));
+        list.retain(|attribute| attribute.name() != "rtcp-mux");
+        assert_eq!(list.to_string(), "a=ptime:30\r\na=mid:0\r\n");
+        assert_eq!(list.remove(1), Attribute::MediaId("0"));
+        assert_eq!(list.to_string(), "a=ptime:30\r\n");
+    }
+
+    #[test]
+    fn test_attribute_list_unknown_lines() {
+        let mut list = AttributeList::new();
+        list.push_unknown_line('y', "first", None);
+        list.push(Attribute::Ptime(20));
+        list.push(Attribute::Property("rtcp-mux"));
+        list.push_unknown_line('x', "vendor", None);
+        list.push(Attribute::MediaId("0"));
+        assert_eq!(list.unknown_lines(), &[('y', "first"), ('x', "vendor")]);
+        assert_eq!(
+            list.to_string(),
+            "y=first\r\na=ptime:20\r\na=rtcp-mux\r\nx=vendor\r\na=mid:0\r\n"
+        );
+
+        list.retain(|attribute| attribute.name() != "rtcp-mux");
+        assert_eq!(
+            list.to_string(),
+            "y=first\r\na=ptime:20\r\nx=vendor\r\na=mid:0\r\n"
+        );
+        list.remove(0);
+        assert_eq!(list.to_string(), "y=first\r\nx=vendor\r\na=mid:0\r\n");
+    }
+}
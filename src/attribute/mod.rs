@@ -0,0 +1,678 @@
+mod candidate;
+mod clock;
+mod direction;
+mod extmap;
+mod fingerprint;
+mod fmtp;
+mod framerate;
+mod group;
+mod imageattr;
+mod list;
+mod msid;
+mod rid;
+mod rtpmap;
+mod setup;
+mod ssrc;
+use std::{borrow::Cow, fmt};
+
+use candidate::parse_candidate;
+pub use candidate::{Candidate, CandidateType};
+use clock::{parse_mediaclk, parse_ts_refclk};
+pub use clock::{MediaClk, TsRefClk};
+pub use direction::Direction;
+use extmap::parse_extmap;
+pub use extmap::ExtMap;
+use fingerprint::parse_fingerprint;
+pub use fingerprint::Fingerprint;
+use fmtp::parse_fmtp;
+pub use fmtp::Fmtp;
+use framerate::parse_framerate;
+pub use framerate::Framerate;
+use group::parse_group;
+pub use group::Group;
+use imageattr::parse_imageattr;
+pub use imageattr::{ImageAttr, ImageAttrSet};
+pub use list::AttributeList;
+use msid::{parse_msid, parse_msid_semantic};
+pub use msid::{Msid, MsidSemantic};
+use nom::{
+    bytes::complete::{tag, take_till1},
+    character::complete::{char, line_ending, not_line_ending, space1, u32},
+    combinator::{all_consuming, opt},
+    error::ParseError,
+    multi::separated_list1,
+    sequence::{preceded, terminated},
+    IResult, Parser,
+};
+
+use crate::session_desription::Rebind;
+use rid::{parse_rid, parse_simulcast};
+pub use rid::{Rid, RidDirection, Simulcast};
+use rtpmap::parse_rtpmap;
+pub use rtpmap::RtpMap;
+use setup::parse_setup;
+pub use setup::Setup;
+use ssrc::{parse_ssrc, parse_ssrc_group};
+pub use ssrc::{Ssrc, SsrcGroup};
+
+/// An `a=` line, either a property attribute or a value attribute.
+///
+/// Attributes this crate has no dedicated type for are kept as-is, so nothing in the
+/// session description is lost.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Attribute<'a> {
+    /// `a=<attribute>`, e.g. `a=recvonly`.
+    Property(&'a str),
+    /// `a=<attribute>:<value>`, e.g. `a=tool:foo`.
+    Value(&'a str, &'a str),
+    /// `a=rtpmap:<payload type> <encoding name>/<clock rate>[/<encoding parameters>]`
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    RtpMap(RtpMap<'a>),
+    /// `a=fmtp:<format> <format specific parameters>`
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    Fmtp(Fmtp<'a>),
+    /// `a=candidate:<foundation> <component-id> <transport> <priority> <address> <port> typ <type> ...`
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    Candidate(Candidate<'a>),
+    /// `a=fingerprint:<hash-func> <fingerprint>`
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    Fingerprint(Fingerprint<'a>),
+    /// `a=setup:<role>`
+    Setup(Setup),
+    /// `a=sendrecv`, `a=sendonly`, `a=recvonly` or `a=inactive`
+    Direction(Direction),
+    /// `a=extmap:<value>["/"<direction>] <URI> <extensionattributes>`
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    ExtMap(ExtMap<'a>),
+    /// `a=ssrc:<ssrc-id> <attribute>[:<value>]`
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    Ssrc(Ssrc<'a>),
+    /// `a=ssrc-group:<semantics> *(<ssrc-id>)`
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    SsrcGroup(SsrcGroup<'a>),
+    /// `a=msid:<stream id> [<track id>]`
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    Msid(Msid<'a>),
+    /// `a=msid-semantic:<semantics> *(<stream id>)`
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    MsidSemantic(MsidSemantic<'a>),
+    /// `a=rid:<rid-id> <direction> [<restrictions>]`
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    Rid(Rid<'a>),
+    /// `a=simulcast:<direction> <streams> [<direction> <streams>]`
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    Simulcast(Simulcast<'a>),
+    /// `a=ptime:<packet time>`, in milliseconds
+    Ptime(u32),
+    /// `a=maxptime:<maximum packet time>`, in milliseconds
+    MaxPtime(u32),
+    /// `a=imageattr:<payload type> send <sets> recv <sets>`
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    ImageAttr(ImageAttr<'a>),
+    /// `a=framerate:<frame rate>`, in frames per second
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    Framerate(Framerate<'a>),
+    /// `a=ts-refclk:<clksrc>[=<value>]`, the reference clock of the RTP timestamps
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    TsRefClk(TsRefClk<'a>),
+    /// `a=mediaclk:<mediaclock>[=<value>] [<parameters>]`
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    MediaClk(MediaClk<'a>),
+    /// `a=ice-options:<ice-option-tag> *(<ice-option-tag>)`
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    IceOptions(Vec<&'a str>),
+    /// `a=sctp-port:<port>`, the SCTP port of a data channel
+    SctpPort(u32),
+    /// `a=max-message-size:<size>`, in bytes, `0` meaning unlimited
+    MaxMessageSize(u32),
+    /// `a=tls-id:<tls-id>`, identifying the DTLS association of the media
+    TlsId(&'a str),
+    /// `a=dtls-id:<dtls-id>`, the draft predecessor of `a=tls-id:`
+    DtlsId(&'a str),
+    /// `a=control:<url>`, the RTSP control URL of the session or of a media stream
+    Control(&'a str),
+    /// `a=charset:<character set>`
+    Charset(&'a str),
+    /// `a=mid:<identification-tag>`
+    MediaId(&'a str),
+    /// `a=group:<semantics> *(<identification-tag>)`
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    Group(Group<'a>),
+}
+
+impl<'a> Attribute<'a> {
+    /// Returns the attribute name, i.e. the part before the `:`.
+    pub fn name(&self) -> &'a str {
+        match self {
+            Attribute::Property(name) => name,
+            Attribute::Value(name, _) => name,
+            Attribute::RtpMap(_) => "rtpmap",
+            Attribute::Fmtp(_) => "fmtp",
+            Attribute::Candidate(_) => "candidate",
+            Attribute::Fingerprint(_) => "fingerprint",
+            Attribute::Setup(_) => "setup",
+            Attribute::Direction(direction) => direction.as_str(),
+            Attribute::ExtMap(_) => "extmap",
+            Attribute::Ssrc(_) => "ssrc",
+            Attribute::SsrcGroup(_) => "ssrc-group",
+            Attribute::Msid(_) => "msid",
+            Attribute::MsidSemantic(_) => "msid-semantic",
+            Attribute::Rid(_) => "rid",
+            Attribute::Simulcast(_) => "simulcast",
+            Attribute::Ptime(_) => "ptime",
+            Attribute::MaxPtime(_) => "maxptime",
+            Attribute::ImageAttr(_) => "imageattr",
+            Attribute::Framerate(_) => "framerate",
+            Attribute::TsRefClk(_) => "ts-refclk",
+            Attribute::MediaClk(_) => "mediaclk",
+            Attribute::IceOptions(_) => "ice-options",
+            Attribute::SctpPort(_) => "sctp-port",
+            Attribute::MaxMessageSize(_) => "max-message-size",
+            Attribute::TlsId(_) => "tls-id",
+            Attribute::DtlsId(_) => "dtls-id",
+            Attribute::Control(_) => "control",
+            Attribute::Charset(_) => "charset",
+            Attribute::MediaId(_) => "mid",
+            Attribute::Group(_) => "group",
+        }
+    }
+
+    /// Returns the raw value of an [`Attribute::Value`], i.e. the part after the `:`.
+    ///
+    /// Property attributes have no value, typed attributes expose theirs through their variant
+    /// or as text through [`Attribute::value_text`].
+    pub fn value(&self) -> Option<&'a str> {
+        match self {
+            Attribute::Value(_, value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the part after the `:` of any attribute that has one, e.g. `20` for the typed
+    /// `a=ptime:20`, as it is serialized. Values of [`Attribute::Value`] are borrowed.
+    pub fn value_text(&self) -> Option<Cow<'a, str>> {
+        if let Some(value) = self.value() {
+            return Some(Cow::Borrowed(value));
+        }
+        let line = self.to_string();
+        let (_, value) = line.trim_end_matches("\r\n").split_once(':')?;
+        Some(Cow::Owned(value.to_owned()))
+    }
+}
+
+impl Rebind for Attribute<'_> {
+    type Output<'b> = Attribute<'b>;
+
+    fn rebind<'b>(&self, strings: &mut dyn FnMut(&str) -> &'b str) -> Attribute<'b> {
+        match self {
+            Attribute::Property(name) => Attribute::Property(strings(name)),
+            Attribute::Value(name, value) => Attribute::Value(strings(name), strings(value)),
+            Attribute::Setup(setup) => Attribute::Setup(setup.clone()),
+            Attribute::Direction(direction) => Attribute::Direction(*direction),
+            Attribute::Ptime(ptime) => Attribute::Ptime(*ptime),
+            Attribute::MaxPtime(maxptime) => Attribute::MaxPtime(*maxptime),
+            Attribute::IceOptions(options) => Attribute::IceOptions(options.rebind(strings)),
+            Attribute::SctpPort(port) => Attribute::SctpPort(*port),
+            Attribute::MaxMessageSize(size) => Attribute::MaxMessageSize(*size),
+            Attribute::TlsId(id) => Attribute::TlsId(strings(id)),
+            Attribute::DtlsId(id) => Attribute::DtlsId(strings(id)),
+            Attribute::Control(url) => Attribute::Control(strings(url)),
+            Attribute::Charset(charset) => Attribute::Charset(strings(charset)),
+            Attribute::MediaId(mid) => Attribute::MediaId(strings(mid)),
+            Attribute::RtpMap(value) => Attribute::RtpMap(value.rebind(strings)),
+            Attribute::Fmtp(value) => Attribute::Fmtp(value.rebind(strings)),
+            Attribute::Candidate(value) => Attribute::Candidate(value.rebind(strings)),
+            Attribute::Fingerprint(value) => Attribute::Fingerprint(value.rebind(strings)),
+            Attribute::ExtMap(value) => Attribute::ExtMap(value.rebind(strings)),
+            Attribute::Ssrc(value) => Attribute::Ssrc(value.rebind(strings)),
+            Attribute::SsrcGroup(value) => Attribute::SsrcGroup(value.rebind(strings)),
+            Attribute::Msid(value) => Attribute::Msid(value.rebind(strings)),
+            Attribute::MsidSemantic(value) => Attribute::MsidSemantic(value.rebind(strings)),
+            Attribute::Rid(value) => Attribute::Rid(value.rebind(strings)),
+            Attribute::Simulcast(value) => Attribute::Simulcast(value.rebind(strings)),
+            Attribute::ImageAttr(value) => Attribute::ImageAttr(value.rebind(strings)),
+            Attribute::Framerate(value) => Attribute::Framerate(value.rebind(strings)),
+            Attribute::TsRefClk(value) => Attribute::TsRefClk(value.rebind(strings)),
+            Attribute::MediaClk(value) => Attribute::MediaClk(value.rebind(strings)),
+            Attribute::Group(value) => Attribute::Group(value.rebind(strings)),
+        }
+    }
+}
+
+impl fmt::Display for Attribute<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Attribute::Property(name) => write!(f, "a={}\r\n", name),
+            Attribute::Value(name, value) => write!(f, "a={}:{}\r\n", name, value),
+            Attribute::RtpMap(rtpmap) => write!(f, "a=rtpmap:{}\r\n", rtpmap),
+            Attribute::Fmtp(fmtp) => write!(f, "a=fmtp:{}\r\n", fmtp),
+            Attribute::Candidate(candidate) => write!(f, "a=candidate:{}\r\n", candidate),
+            Attribute::Fingerprint(fingerprint) => {
+                write!(f, "a=fingerprint:{}\r\n", fingerprint)
+            }
+            Attribute::Setup(setup) => write!(f, "a=setup:{}\r\n", setup),
+            Attribute::Direction(direction) => write!(f, "a={}\r\n", direction),
+            Attribute::ExtMap(extmap) => write!(f, "a=extmap:{}\r\n", extmap),
+            Attribute::Ssrc(ssrc) => write!(f, "a=ssrc:{}\r\n", ssrc),
+            Attribute::SsrcGroup(group) => write!(f, "a=ssrc-group:{}\r\n", group),
+            Attribute::Msid(msid) => write!(f, "a=msid:{}\r\n", msid),
+            Attribute::MsidSemantic(semantic) => write!(f, "a=msid-semantic:{}\r\n", semantic),
+            Attribute::Rid(rid) => write!(f, "a=rid:{}\r\n", rid),
+            Attribute::Simulcast(simulcast) => write!(f, "a=simulcast:{}\r\n", simulcast),
+            Attribute::Ptime(ptime) => write!(f, "a=ptime:{}\r\n", ptime),
+            Attribute::MaxPtime(maxptime) => write!(f, "a=maxptime:{}\r\n", maxptime),
+            Attribute::ImageAttr(imageattr) => write!(f, "a=imageattr:{}\r\n", imageattr),
+            Attribute::Framerate(framerate) => write!(f, "a=framerate:{}\r\n", framerate),
+            Attribute::TsRefClk(refclk) => write!(f, "a=ts-refclk:{}\r\n", refclk),
+            Attribute::MediaClk(mediaclk) => write!(f, "a=mediaclk:{}\r\n", mediaclk),
+            Attribute::IceOptions(options) => write!(f, "a=ice-options:{}\r\n", options.join(" ")),
+            Attribute::SctpPort(port) => write!(f, "a=sctp-port:{}\r\n", port),
+            Attribute::MaxMessageSize(size) => write!(f, "a=max-message-size:{}\r\n", size),
+            Attribute::TlsId(id) => write!(f, "a=tls-id:{}\r\n", id),
+            Attribute::DtlsId(id) => write!(f, "a=dtls-id:{}\r\n", id),
+            Attribute::Control(control) => write!(f, "a=control:{}\r\n", control),
+            Attribute::Charset(charset) => write!(f, "a=charset:{}\r\n", charset),
+            Attribute::MediaId(mid) => write!(f, "a=mid:{}\r\n", mid),
+            Attribute::Group(group) => write!(f, "a=group:{}\r\n", group),
+        }
+    }
+}
+
+/// a=<attribute>
+/// a=<attribute>:<value>
+/// see https://tools.ietf.org/html/rfc8866#section-5.13
+pub fn parse_attribute<'i, E: ParseError<&'i str>>(
+    input: &'i str,
+) -> IResult<&'i str, Attribute<'i>, E> {
+    let (tail, (name, value)) = parse_name_value(input)?;
+    let attribute = match (name, value) {
+        ("rtpmap", Some(value)) => Attribute::RtpMap(parse_value(parse_rtpmap, value)?),
+        ("fmtp", Some(value)) => Attribute::Fmtp(parse_value(parse_fmtp, value)?),
+        ("candidate", Some(value)) => Attribute::Candidate(parse_value(parse_candidate, value)?),
+        ("fingerprint", Some(value)) => {
+            Attribute::Fingerprint(parse_value(parse_fingerprint, value)?)
+        }
+        ("setup", Some(value)) => Attribute::Setup(parse_value(parse_setup, value)?),
+        ("extmap", Some(value)) => Attribute::ExtMap(parse_value(parse_extmap, value)?),
+        ("ssrc", Some(value)) => Attribute::Ssrc(parse_value(parse_ssrc, value)?),
+        ("ssrc-group", Some(value)) => Attribute::SsrcGroup(parse_value(parse_ssrc_group, value)?),
+        ("msid", Some(value)) => Attribute::Msid(parse_value(parse_msid, value)?),
+        ("msid-semantic", Some(value)) => {
+            Attribute::MsidSemantic(parse_value(parse_msid_semantic, value)?)
+        }
+        ("rid", Some(value)) => Attribute::Rid(parse_value(parse_rid, value)?),
+        ("simulcast", Some(value)) => Attribute::Simulcast(parse_value(parse_simulcast, value)?),
+        ("ptime", Some(value)) => Attribute::Ptime(parse_value(u32, value)?),
+        ("maxptime", Some(value)) => Attribute::MaxPtime(parse_value(u32, value)?),
+        ("imageattr", Some(value)) => Attribute::ImageAttr(parse_value(parse_imageattr, value)?),
+        ("framerate", Some(value)) => Attribute::Framerate(parse_value(parse_framerate, value)?),
+        ("ts-refclk", Some(value)) => Attribute::TsRefClk(parse_value(parse_ts_refclk, value)?),
+        ("mediaclk", Some(value)) => Attribute::MediaClk(parse_value(parse_mediaclk, value)?),
+        ("ice-options", Some(value)) => {
+            Attribute::IceOptions(parse_value(separated_list1(space1, parse_token), value)?)
+        }
+        ("sctp-port", Some(value)) => Attribute::SctpPort(parse_value(u32, value)?),
+        ("max-message-size", Some(value)) => Attribute::MaxMessageSize(parse_value(u32, value)?),
+        ("tls-id", Some(value)) => Attribute::TlsId(parse_value(parse_token, value)?),
+        ("dtls-id", Some(value)) => Attribute::DtlsId(parse_value(parse_token, value)?),
+        ("control", Some(value)) if !value.is_empty() => Attribute::Control(value),
+        ("charset", Some(value)) => Attribute::Charset(parse_value(parse_token, value)?),
+        ("mid", Some(value)) => Attribute::MediaId(parse_value(parse_token, value)?),
+        ("group", Some(value)) => Attribute::Group(parse_value(parse_group, value)?),
+        (name, value) => untyped_attribute(name, value),
+    };
+    Ok((tail, attribute))
+}
+
+/// Parses an `a=` line without interpreting its value, as an [`Attribute::Value`] (or
+/// [`Attribute::Property`] and [`Attribute::Direction`] for the lines without value).
+pub(crate) fn parse_untyped_attribute<'i, E: ParseError<&'i str>>(
+    input: &'i str,
+) -> IResult<&'i str, Attribute<'i>, E> {
+    let (tail, (name, value)) = parse_name_value(input)?;
+    Ok((tail, untyped_attribute(name, value)))
+}
+
+fn parse_name_value<'i, E: ParseError<&'i str>>(
+    input: &'i str,
+) -> IResult<&'i str, (&'i str, Option<&'i str>), E> {
+    let (tail, _) = tag("a=").parse(input)?;
+    let (tail, name) = take_till1(|c: char| c == ':' || c.is_whitespace()).parse(tail)?;
+    let (tail, value) =
+        terminated(opt(preceded(char(':'), not_line_ending)), opt(line_ending)).parse(tail)?;
+    Ok((tail, (name, value)))
+}
+
+fn untyped_attribute<'i>(name: &'i str, value: Option<&'i str>) -> Attribute<'i> {
+    match value {
+        Some(value) => Attribute::Value(name, value),
+        None => match Direction::from_name(name) {
+            Some(direction) => Attribute::Direction(direction),
+            None => Attribute::Property(name),
+        },
+    }
+}
+
+/// Parses a whitespace-free token such as an `a=mid:` identification tag.
+fn parse_token<'i, E: ParseError<&'i str>>(input: &'i str) -> IResult<&'i str, &'i str, E> {
+    take_till1(|c: char| c.is_whitespace()).parse(input)
+}
+
+/// Runs the parser of a typed attribute over the whole attribute value.
+fn parse_value<'i, O, E: ParseError<&'i str>>(
+    parser: impl Parser<&'i str, O, E>,
+    value: &'i str,
+) -> Result<O, nom::Err<E>> {
+    all_consuming(parser).parse(value).map(|(_, value)| value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_property_attribute() {
+        let (tail, value) = parse_attribute::<()>("a=rtcp-mux\r\n").unwrap();
+        assert_eq!(tail, "");
+        assert_eq!(value, Attribute::Property("rtcp-mux"));
+        assert_eq!(value.name(), "rtcp-mux");
+        assert_eq!(value.value(), None);
+    }
+
+    #[test]
+    fn test_parse_direction_attribute() {
+        let (tail, value) = parse_attribute::<()>("a=recvonly\r\n").unwrap();
+        assert_eq!(tail, "");
+        assert_eq!(value, Attribute::Direction(Direction::RecvOnly));
+        assert_eq!(value.name(), "recvonly");
+        assert_eq!(value.to_string(), "a=recvonly\r\n");
+    }
+
+    #[test]
+    fn test_parse_value_attribute() {
+        let (tail, value) = parse_attribute::<()>("a=tool:sdp-parser 0.1\r\n").unwrap();
+        assert_eq!(tail, "");
+        assert_eq!(value, Attribute::Value("tool", "sdp-parser 0.1"));
+        assert_eq!(value.name(), "tool");
+        assert_eq!(value.value(), Some("sdp-parser 0.1"));
+        assert_eq!(value.value_text().as_deref(), Some("sdp-parser 0.1"));
+    }
+
+    #[test]
+    fn test_typed_attribute_value_text() {
+        let (_, value) = parse_attribute::<()>("a=ptime:20\r\n").unwrap();
+        assert_eq!(value, Attribute::Ptime(20));
+        assert_eq!(value.value(), None);
+        assert_eq!(value.value_text().as_deref(), Some("20"));
+
+        let (_, value) = parse_attribute::<()>("a=recvonly\r\n").unwrap();
+        assert_eq!(value.value_text(), None);
+    }
+
+    #[test]
+    fn test_parse_rtpmap_attribute() {
+        let (tail, value) = parse_attribute::<()>("a=rtpmap:96 opus/48000/2\r\n").unwrap();
+        assert_eq!(tail, "");
+        assert_eq!(
+            value,
+            Attribute::RtpMap(RtpMap::new(96, "opus", 48000, Some(2)))
+        );
+        assert_eq!(value.name(), "rtpmap");
+        assert_eq!(value.value(), None);
+    }
+
+    #[test]
+    fn test_parse_malformed_rtpmap_attribute() {
+        assert!(parse_attribute::<()>("a=rtpmap:96 opus\r\n").is_err());
+        assert!(parse_attribute::<()>("a=rtpmap:96 opus/48000/2 trailing\r\n").is_err());
+    }
+
+    #[test]
+    fn test_parse_fmtp_attribute() {
+        let input = "a=fmtp:96 minptime=10;useinbandfec=1\r\n";
+        let (tail, value) = parse_attribute::<()>(input).unwrap();
+        assert_eq!(tail, "");
+        assert_eq!(
+            value,
+            Attribute::Fmtp(Fmtp::new(96, "minptime=10;useinbandfec=1"))
+        );
+        assert_eq!(value.to_string(), input);
+    }
+
+    #[test]
+    fn test_parse_candidate_attribute() {
+        let input = "a=candidate:1 1 UDP 2130706431 192.168.1.1 54321 typ host\r\n";
+        let (tail, value) = parse_attribute::<()>(input).unwrap();
+        assert_eq!(tail, "");
+        match &value {
+            Attribute::Candidate(candidate) => {
+                assert_eq!(candidate.candidate_type(), &CandidateType::Host)
+            }
+            _ => panic!("unexpected attribute"),
+        }
+        assert_eq!(value.to_string(), input);
+    }
+
+    #[test]
+    fn test_parse_fingerprint_attribute() {
+        let input = "a=fingerprint:sha-1 4A:AD:B9:B1\r\n";
+        let (tail, value) = parse_attribute::<()>(input).unwrap();
+        assert_eq!(tail, "");
+        assert_eq!(
+            value,
+            Attribute::Fingerprint(Fingerprint::new("sha-1", "4A:AD:B9:B1"))
+        );
+        assert_eq!(value.to_string(), input);
+        assert!(parse_attribute::<()>("a=fingerprint:sha-1 4A:AD:B9:B\r\n").is_err());
+    }
+
+    #[test]
+    fn test_parse_setup_attribute() {
+        let (tail, value) = parse_attribute::<()>("a=setup:actpass\r\n").unwrap();
+        assert_eq!(tail, "");
+        assert_eq!(value, Attribute::Setup(Setup::ActPass));
+        assert_eq!(value.to_string(), "a=setup:actpass\r\n");
+        assert!(parse_attribute::<()>("a=setup:actpassive\r\n").is_err());
+    }
+
+    #[test]
+    fn test_parse_extmap_attribute() {
+        let input = "a=extmap:3 http://www.ietf.org/id/draft-holmer-rmcat-transport-wide-cc-extensions-01\r\n";
+        let (tail, value) = parse_attribute::<()>(input).unwrap();
+        assert_eq!(tail, "");
+        assert_eq!(value.name(), "extmap");
+        assert!(matches!(&value, Attribute::ExtMap(extmap) if extmap.id() == 3));
+        assert_eq!(value.to_string(), input);
+        assert!(parse_attribute::<()>("a=extmap:0 urn:x\r\n").is_err());
+    }
+
+    #[test]
+    fn test_parse_charset_attribute() {
+        let (tail, value) = parse_attribute::<()>("a=charset:ISO-8859-1\r\n").unwrap();
+        assert_eq!(tail, "");
+        assert_eq!(value, Attribute::Charset("ISO-8859-1"));
+        assert_eq!(value.to_string(), "a=charset:ISO-8859-1\r\n");
+    }
+
+    #[test]
+    fn test_parse_tls_id_attributes() {
+        let input = "a=tls-id:abc3de65cddef001be82\r\n";
+        let (tail, value) = parse_attribute::<()>(input).unwrap();
+        assert_eq!(tail, "");
+        assert_eq!(value, Attribute::TlsId("abc3de65cddef001be82"));
+        assert_eq!(value.to_string(), input);
+        let (_, value) = parse_attribute::<()>("a=dtls-id:1\r\n").unwrap();
+        assert_eq!(value, Attribute::DtlsId("1"));
+        assert_eq!(value.name(), "dtls-id");
+        assert!(parse_attribute::<()>("a=tls-id:a b\r\n").is_err());
+    }
+
+    #[test]
+    fn test_parse_control_attribute() {
+        for input in [
+            "a=control:trackID=1\r\n",
+            "a=control:rtsp://example.com/movie/audio\r\n",
+            "a=control:*\r\n",
+        ] {
+            let (tail, value) = parse_attribute::<()>(input).unwrap();
+            assert_eq!(tail, "");
+            assert!(matches!(value, Attribute::Control(_)));
+            assert_eq!(value.to_string(), input);
+        }
+        let (_, value) = parse_attribute::<()>("a=control:\r\n").unwrap();
+        assert_eq!(value, Attribute::Value("control", ""));
+    }
+
+    #[test]
+    fn test_parse_mid_attribute() {
+        let (tail, value) = parse_attribute::<()>("a=mid:audio\r\n").unwrap();
+        assert_eq!(tail, "");
+        assert_eq!(value, Attribute::MediaId("audio"));
+        assert_eq!(value.to_string(), "a=mid:audio\r\n");
+        assert!(parse_attribute::<()>("a=mid:\r\n").is_err());
+    }
+
+    #[test]
+    fn test_parse_group_attribute() {
+        let (tail, value) = parse_attribute::<()>("a=group:BUNDLE audio video\r\n").unwrap();
+        assert_eq!(tail, "");
+        assert_eq!(
+            value,
+            Attribute::Group(Group::new("BUNDLE", vec!["audio", "video"]))
+        );
+    }
+
+    #[test]
+    fn test_parse_ice_options_attribute() {
+        let (_, value) =
+            parse_attribute::<()>("a=ice-options:trickle renomination trickle\r\n").unwrap();
+        assert_eq!(
+            value,
+            Attribute::IceOptions(vec!["trickle", "renomination", "trickle"])
+        );
+        assert_eq!(
+            value.to_string(),
+            "a=ice-options:trickle renomination trickle\r\n"
+        );
+        assert!(parse_attribute::<()>("a=ice-options:\r\n").is_err());
+    }
+
+    #[test]
+    fn test_parse_sctp_attributes() {
+        let (_, value) = parse_attribute::<()>("a=sctp-port:5000\r\n").unwrap();
+        assert_eq!(value, Attribute::SctpPort(5000));
+        assert_eq!(value.to_string(), "a=sctp-port:5000\r\n");
+        let (_, value) = parse_attribute::<()>("a=max-message-size:262144\r\n").unwrap();
+        assert_eq!(value, Attribute::MaxMessageSize(262144));
+        assert_eq!(value.to_string(), "a=max-message-size:262144\r\n");
+        assert!(parse_attribute::<()>("a=sctp-port:port\r\n").is_err());
+        assert!(parse_attribute::<()>("a=max-message-size:-1\r\n").is_err());
+    }
+
+    #[test]
+    fn test_parse_ptime_attributes() {
+        let (_, value) = parse_attribute::<()>("a=ptime:20\r\n").unwrap();
+        assert_eq!(value, Attribute::Ptime(20));
+        assert_eq!(value.to_string(), "a=ptime:20\r\n");
+        let (_, value) = parse_attribute::<()>("a=maxptime:120\r\n").unwrap();
+        assert_eq!(value, Attribute::MaxPtime(120));
+        assert!(parse_attribute::<()>("a=ptime:twenty\r\n").is_err());
+        assert!(parse_attribute::<()>("a=maxptime:120ms\r\n").is_err());
+    }
+
+    #[test]
+    fn test_parse_rid_attributes() {
+        let input = "a=rid:hi send pt=96;max-width=1280\r\n";
+        let (_, value) = parse_attribute::<()>(input).unwrap();
+        assert_eq!(
+            value,
+            Attribute::Rid(Rid::new(
+                "hi",
+                RidDirection::Send,
+                Some("pt=96;max-width=1280")
+            ))
+        );
+        assert_eq!(value.to_string(), input);
+        let input = "a=simulcast:send hi;mid;lo\r\n";
+        let (_, value) = parse_attribute::<()>(input).unwrap();
+        assert_eq!(value.name(), "simulcast");
+        assert_eq!(value.to_string(), input);
+        assert!(parse_attribute::<()>("a=rid:hi\r\n").is_err());
+        assert!(parse_attribute::<()>("a=simulcast:send\r\n").is_err());
+    }
+
+    #[test]
+    fn test_parse_imageattr_attribute() {
+        let input = "a=imageattr:96 send [x=1280,y=720] recv [x=640,y=480]\r\n";
+        let (tail, value) = parse_attribute::<()>(input).unwrap();
+        assert_eq!(tail, "");
+        assert!(
+            matches!(&value, Attribute::ImageAttr(imageattr) if imageattr.payload_type() == Some(96))
+        );
+        assert_eq!(value.name(), "imageattr");
+        assert_eq!(value.to_string(), input);
+        assert!(parse_attribute::<()>("a=imageattr:96 send [x=1280,y=720] trailing\r\n").is_err());
+    }
+
+    #[test]
+    fn test_parse_framerate_attribute() {
+        let (_, value) = parse_attribute::<()>("a=framerate:29.97\r\n").unwrap();
+        assert_eq!(value, Attribute::Framerate(Framerate::new("29.97")));
+        assert_eq!(value.to_string(), "a=framerate:29.97\r\n");
+        let (_, value) = parse_attribute::<()>("a=framerate:30\r\n").unwrap();
+        assert_eq!(value, Attribute::Framerate(Framerate::new("30")));
+        assert!(parse_attribute::<()>("a=framerate:29.\r\n").is_err());
+        assert!(parse_attribute::<()>("a=framerate:29,97\r\n").is_err());
+    }
+
+    #[test]
+    fn test_parse_clock_attributes() {
+        let input = "a=ts-refclk:ptp=IEEE1588-2008:39-A7-94-FF-FE-07-CB-D0:37\r\n";
+        let (_, value) = parse_attribute::<()>(input).unwrap();
+        assert!(matches!(&value, Attribute::TsRefClk(refclk) if refclk.source() == "ptp"));
+        assert_eq!(value.name(), "ts-refclk");
+        assert_eq!(value.to_string(), input);
+
+        let (_, value) = parse_attribute::<()>("a=mediaclk:direct=0\r\n").unwrap();
+        assert_eq!(
+            value,
+            Attribute::MediaClk(MediaClk::new("direct", Some("0"), None))
+        );
+        assert_eq!(value.to_string(), "a=mediaclk:direct=0\r\n");
+        assert!(parse_attribute::<()>("a=ts-refclk:\r\n").is_err());
+    }
+
+    #[test]
+    fn test_parse_attribute_empty_value() {
+        let (_, value) = parse_attribute::<()>("a=tool:\r\n").unwrap();
+        assert_eq!(value, Attribute::Value("tool", ""));
+    }
+
+    #[test]
+    fn test_parse_untyped_attribute() {
+        let (tail, value) = parse_untyped_attribute::<()>("a=rtpmap:0 PCMU\r\n").unwrap();
+        assert_eq!(tail, "");
+        assert_eq!(value, Attribute::Value("rtpmap", "0 PCMU"));
+        assert_eq!(value.to_string(), "a=rtpmap:0 PCMU\r\n");
+        let (_, value) = parse_untyped_attribute::<()>("a=sendonly\r\n").unwrap();
+        assert_eq!(value, Attribute::Direction(Direction::SendOnly));
+    }
+
+    #[test]
+    fn test_attribute_display() {
+        for input in [
+            "a=recvonly\r\n",
+            "a=rtpmap:96 opus/48000/2\r\n",
+            "a=tool:\r\n",
+        ] {
+            let (_, value) = parse_attribute::<()>(input).unwrap();
+            assert_eq!(value.to_string(), input);
+        }
+    }
+
+    #[test]
+    fn test_parse_attribute_without_name() {
+        assert!(parse_attribute::<()>("a=:value\r\n").is_err());
+    }
+}
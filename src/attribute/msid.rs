@@ -0,0 +1,164 @@
+use std::fmt;
+
+use nom::{
+    character::complete::{space0, space1},
+    combinator::opt,
+    error::ParseError,
+    multi::many0,
+    sequence::preceded,
+    IResult, Parser,
+};
+
+use super::parse_token;
+use crate::session_desription::Rebind;
+
+/// The value of an `a=msid:` attribute, associating a media section with a media stream and,
+/// optionally, one of its tracks.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Msid<'a> {
+    stream_id: &'a str,
+    track_id: Option<&'a str>,
+}
+
+impl<'a> Msid<'a> {
+    pub fn new(stream_id: &'a str, track_id: Option<&'a str>) -> Self {
+        Self {
+            stream_id,
+            track_id,
+        }
+    }
+
+    /// Returns the identifier of the media stream, `-` if the track belongs to no stream.
+    pub fn stream_id(&self) -> &'a str {
+        self.stream_id
+    }
+
+    /// Returns the identifier of the track within the media stream, if given.
+    pub fn track_id(&self) -> Option<&'a str> {
+        self.track_id
+    }
+}
+
+impl Rebind for Msid<'_> {
+    type Output<'b> = Msid<'b>;
+
+    fn rebind<'b>(&self, strings: &mut dyn FnMut(&str) -> &'b str) -> Msid<'b> {
+        Msid {
+            stream_id: strings(self.stream_id),
+            track_id: self.track_id.rebind(strings),
+        }
+    }
+}
+
+impl fmt::Display for Msid<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.stream_id)?;
+        if let Some(track_id) = self.track_id {
+            write!(f, " {}", track_id)?;
+        }
+        Ok(())
+    }
+}
+
+/// The value of an `a=msid-semantic:` attribute, listing the media streams of the session.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MsidSemantic<'a> {
+    semantics: &'a str,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    stream_ids: Vec<&'a str>,
+}
+
+impl<'a> MsidSemantic<'a> {
+    pub fn new(semantics: &'a str, stream_ids: Vec<&'a str>) -> Self {
+        Self {
+            semantics,
+            stream_ids,
+        }
+    }
+
+    /// Returns the semantics, e.g. `WMS`.
+    pub fn semantics(&self) -> &'a str {
+        self.semantics
+    }
+
+    /// Returns the identifiers of the media streams, `*` standing for all of them.
+    pub fn stream_ids(&self) -> &[&'a str] {
+        &self.stream_ids
+    }
+}
+
+impl Rebind for MsidSemantic<'_> {
+    type Output<'b> = MsidSemantic<'b>;
+
+    fn rebind<'b>(&self, strings: &mut dyn FnMut(&str) -> &'b str) -> MsidSemantic<'b> {
+        MsidSemantic {
+            semantics: strings(self.semantics),
+            stream_ids: self.stream_ids.rebind(strings),
+        }
+    }
+}
+
+impl fmt::Display for MsidSemantic<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, " {}", self.semantics)?;
+        for stream_id in &self.stream_ids {
+            write!(f, " {}", stream_id)?;
+        }
+        Ok(())
+    }
+}
+
+/// a=msid:<stream id> [<track id>]
+/// a=msid:61317484-2ed4-49d7-9eb7-1414322a7aae f30bdb4a-5db8-49b5-bcdc-e0c9a23172e0
+/// see https://tools.ietf.org/html/rfc8830#section-2
+pub fn parse_msid<'i, E: ParseError<&'i str>>(input: &'i str) -> IResult<&'i str, Msid<'i>, E> {
+    let (tail, stream_id) = parse_token(input)?;
+    let (tail, track_id) = opt(preceded(space1, parse_token)).parse(tail)?;
+    Ok((tail, Msid::new(stream_id, track_id)))
+}
+
+/// a=msid-semantic:<semantics> *(<stream id>)
+/// a=msid-semantic: WMS 61317484-2ed4-49d7-9eb7-1414322a7aae
+/// see https://tools.ietf.org/html/draft-ietf-mmusic-msid-05#section-4
+pub fn parse_msid_semantic<'i, E: ParseError<&'i str>>(
+    input: &'i str,
+) -> IResult<&'i str, MsidSemantic<'i>, E> {
+    let (tail, semantics) = preceded(space0, parse_token).parse(input)?;
+    let (tail, stream_ids) = many0(preceded(space1, parse_token)).parse(tail)?;
+    Ok((tail, MsidSemantic::new(semantics, stream_ids)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_msid() {
+        let input = "stream track";
+        let (tail, value) = parse_msid::<()>(input).unwrap();
+        assert_eq!(tail, "");
+        assert_eq!(value, Msid::new("stream", Some("track")));
+        assert_eq!(value.to_string(), input);
+    }
+
+    #[test]
+    fn test_parse_msid_stream_only() {
+        let (tail, value) = parse_msid::<()>("stream").unwrap();
+        assert_eq!(tail, "");
+        assert_eq!(value.stream_id(), "stream");
+        assert_eq!(value.track_id(), None);
+        assert_eq!(value.to_string(), "stream");
+    }
+
+    #[test]
+    fn test_parse_msid_semantic() {
+        for input in [" WMS stream *", "WMS stream *"] {
+            let (tail, value) = parse_msid_semantic::<()>(input).unwrap();
+            assert_eq!(tail, "");
+            assert_eq!(value, MsidSemantic::new("WMS", vec!["stream", "*"]));
+            assert_eq!(value.to_string(), " WMS stream *");
+        }
+    }
+}
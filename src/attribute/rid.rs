@@ -0,0 +1,284 @@
+use std::fmt;
+
+use nom::{
+    branch::alt,
+    bytes::complete::{tag, take_till1},
+    character::complete::{char, space1},
+    combinator::{map, opt, rest},
+    error::ParseError,
+    multi::separated_list1,
+    sequence::{preceded, tuple},
+    IResult, Parser,
+};
+
+use super::parse_token;
+use crate::session_desription::Rebind;
+
+/// The direction of an `a=rid:` restriction or of an `a=simulcast:` stream list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RidDirection {
+    Send,
+    Recv,
+}
+
+impl RidDirection {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RidDirection::Send => "send",
+            RidDirection::Recv => "recv",
+        }
+    }
+}
+
+impl fmt::Display for RidDirection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// The value of an `a=rid:` attribute, restricting one of the RTP streams of a media section.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Rid<'a> {
+    id: &'a str,
+    direction: RidDirection,
+    restrictions: Option<&'a str>,
+}
+
+impl<'a> Rid<'a> {
+    pub fn new(id: &'a str, direction: RidDirection, restrictions: Option<&'a str>) -> Self {
+        Self {
+            id,
+            direction,
+            restrictions,
+        }
+    }
+
+    /// Returns the RTP stream identifier, e.g. `hi`.
+    pub fn id(&self) -> &'a str {
+        self.id
+    }
+
+    pub fn direction(&self) -> RidDirection {
+        self.direction
+    }
+
+    /// Returns the raw restrictions, e.g. `pt=96;max-width=1280`.
+    pub fn restrictions(&self) -> Option<&'a str> {
+        self.restrictions
+    }
+
+    /// Returns the `key=value` pairs of the restrictions, in the order they appeared.
+    ///
+    /// Restrictions given without a value map to an empty string.
+    pub fn restriction_pairs(&self) -> impl Iterator<Item = (&'a str, &'a str)> {
+        self.restrictions
+            .unwrap_or_default()
+            .split(';')
+            .filter(|restriction| !restriction.is_empty())
+            .map(|restriction| restriction.split_once('=').unwrap_or((restriction, "")))
+    }
+
+    /// Returns the value of the restriction `key`, e.g. `1280` for `max-width`.
+    pub fn get(&self, key: &str) -> Option<&'a str> {
+        self.restriction_pairs()
+            .find(|(name, _)| *name == key)
+            .map(|(_, value)| value)
+    }
+
+    /// Returns the payload types the stream is restricted to (`pt=`), in the order they
+    /// appeared. Empty if the stream may use any payload type of the media section.
+    pub fn payload_types(&self) -> impl Iterator<Item = &'a str> {
+        self.get("pt")
+            .into_iter()
+            .flat_map(|formats| formats.split(','))
+    }
+
+    /// Returns the maximum width (`max-width=`) in pixels, if restricted.
+    pub fn max_width(&self) -> Option<u32> {
+        self.get("max-width")?.parse().ok()
+    }
+
+    /// Returns the maximum height (`max-height=`) in pixels, if restricted.
+    pub fn max_height(&self) -> Option<u32> {
+        self.get("max-height")?.parse().ok()
+    }
+}
+
+impl Rebind for Rid<'_> {
+    type Output<'b> = Rid<'b>;
+
+    fn rebind<'b>(&self, strings: &mut dyn FnMut(&str) -> &'b str) -> Rid<'b> {
+        Rid {
+            id: strings(self.id),
+            direction: self.direction,
+            restrictions: self.restrictions.rebind(strings),
+        }
+    }
+}
+
+impl fmt::Display for Rid<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.id, self.direction)?;
+        if let Some(restrictions) = self.restrictions {
+            write!(f, " {}", restrictions)?;
+        }
+        Ok(())
+    }
+}
+
+/// The value of an `a=simulcast:` attribute, listing the simulcast streams sent and received.
+///
+/// Each direction holds a list of streams, each given as a list of alternative RIDs. A RID
+/// starting with `~` is paused.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Simulcast<'a> {
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    send: Vec<Vec<&'a str>>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    recv: Vec<Vec<&'a str>>,
+}
+
+impl<'a> Simulcast<'a> {
+    pub fn new(send: Vec<Vec<&'a str>>, recv: Vec<Vec<&'a str>>) -> Self {
+        Self { send, recv }
+    }
+
+    /// Returns the streams sent, e.g. `[["hi"], ["mid"], ["lo"]]` for `send hi;mid;lo`.
+    pub fn send(&self) -> &[Vec<&'a str>] {
+        &self.send
+    }
+
+    /// Returns the streams received.
+    pub fn recv(&self) -> &[Vec<&'a str>] {
+        &self.recv
+    }
+}
+
+impl Rebind for Simulcast<'_> {
+    type Output<'b> = Simulcast<'b>;
+
+    fn rebind<'b>(&self, strings: &mut dyn FnMut(&str) -> &'b str) -> Simulcast<'b> {
+        Simulcast {
+            send: self.send.rebind(strings),
+            recv: self.recv.rebind(strings),
+        }
+    }
+}
+
+impl fmt::Display for Simulcast<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut separator = "";
+        for (direction, streams) in [
+            (RidDirection::Send, &self.send),
+            (RidDirection::Recv, &self.recv),
+        ] {
+            if streams.is_empty() {
+                continue;
+            }
+            let streams: Vec<String> = streams.iter().map(|stream| stream.join(",")).collect();
+            write!(f, "{}{} {}", separator, direction, streams.join(";"))?;
+            separator = " ";
+        }
+        Ok(())
+    }
+}
+
+fn parse_rid_direction<'i, E: ParseError<&'i str>>(
+    input: &'i str,
+) -> IResult<&'i str, RidDirection, E> {
+    alt((
+        map(tag("send"), |_| RidDirection::Send),
+        map(tag("recv"), |_| RidDirection::Recv),
+    ))
+    .parse(input)
+}
+
+/// a=rid:<rid-id> <direction> [<restrictions>]
+/// a=rid:hi send pt=96;max-width=1280
+/// see https://tools.ietf.org/html/rfc8851#section-4
+pub fn parse_rid<'i, E: ParseError<&'i str>>(input: &'i str) -> IResult<&'i str, Rid<'i>, E> {
+    let (tail, id) = parse_token(input)?;
+    let (tail, direction) = preceded(space1, parse_rid_direction).parse(tail)?;
+    let (tail, restrictions) = opt(preceded(space1, rest)).parse(tail)?;
+    Ok((tail, Rid::new(id, direction, restrictions)))
+}
+
+/// Parses a `;`-separated list of streams, each a `,`-separated list of alternative RIDs.
+fn parse_simulcast_streams<'i, E: ParseError<&'i str>>(
+    input: &'i str,
+) -> IResult<&'i str, Vec<Vec<&'i str>>, E> {
+    let rid = take_till1(|c: char| c == ',' || c == ';' || c.is_whitespace());
+    separated_list1(char(';'), separated_list1(char(','), rid)).parse(input)
+}
+
+/// a=simulcast:<direction> <streams> [<direction> <streams>]
+/// a=simulcast:send hi;mid;lo recv ~f
+/// see https://tools.ietf.org/html/rfc8853#section-5.1
+pub fn parse_simulcast<'i, E: ParseError<&'i str>>(
+    input: &'i str,
+) -> IResult<&'i str, Simulcast<'i>, E> {
+    let (tail, first) = parse_rid_direction(input)?;
+    let (tail, first_streams) = preceded(space1, parse_simulcast_streams).parse(tail)?;
+    let other = match first {
+        RidDirection::Send => tag("recv"),
+        RidDirection::Recv => tag("send"),
+    };
+    let (tail, other_streams) = opt(preceded(
+        tuple((space1, other, space1)),
+        parse_simulcast_streams,
+    ))
+    .parse(tail)?;
+    let other_streams = other_streams.unwrap_or_default();
+    let simulcast = match first {
+        RidDirection::Send => Simulcast::new(first_streams, other_streams),
+        RidDirection::Recv => Simulcast::new(other_streams, first_streams),
+    };
+    Ok((tail, simulcast))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_rid() {
+        let input = "hi send pt=96,97;max-width=1280;max-height=720";
+        let (tail, value) = parse_rid::<()>(input).unwrap();
+        assert_eq!(tail, "");
+        assert_eq!(value.id(), "hi");
+        assert_eq!(value.direction(), RidDirection::Send);
+        assert_eq!(value.payload_types().collect::<Vec<_>>(), ["96", "97"]);
+        assert_eq!(value.max_width(), Some(1280));
+        assert_eq!(value.max_height(), Some(720));
+        assert_eq!(value.get("max-fps"), None);
+        assert_eq!(value.to_string(), input);
+    }
+
+    #[test]
+    fn test_parse_rid_without_restrictions() {
+        let (tail, value) = parse_rid::<()>("lo recv").unwrap();
+        assert_eq!(tail, "");
+        assert_eq!(value, Rid::new("lo", RidDirection::Recv, None));
+        assert_eq!(value.payload_types().count(), 0);
+        assert_eq!(value.to_string(), "lo recv");
+        assert!(parse_rid::<()>("lo both").is_err());
+    }
+
+    #[test]
+    fn test_parse_simulcast() {
+        let (tail, value) = parse_simulcast::<()>("send hi;mid;lo").unwrap();
+        assert_eq!(tail, "");
+        assert_eq!(value.send(), [vec!["hi"], vec!["mid"], vec!["lo"]]);
+        assert!(value.recv().is_empty());
+        assert_eq!(value.to_string(), "send hi;mid;lo");
+
+        let (tail, value) = parse_simulcast::<()>("recv 1,~2;3 send 4").unwrap();
+        assert_eq!(tail, "");
+        assert_eq!(value.send(), [vec!["4"]]);
+        assert_eq!(value.recv(), [vec!["1", "~2"], vec!["3"]]);
+        assert_eq!(value.to_string(), "send 4 recv 1,~2;3");
+    }
+}
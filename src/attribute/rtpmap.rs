@@ -0,0 +1,127 @@
+use std::fmt;
+
+use nom::{
+    bytes::complete::take_till1,
+    character::complete::{char, space1, u32, u8},
+    combinator::opt,
+    error::ParseError,
+    sequence::{preceded, terminated},
+    IResult, Parser,
+};
+
+use crate::session_desription::Rebind;
+
+/// The value of an `a=rtpmap:` attribute, mapping an RTP payload type to an encoding.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RtpMap<'a> {
+    payload_type: u8,
+    encoding_name: &'a str,
+    clock_rate: u32,
+    channels: Option<u8>,
+}
+
+impl<'a> RtpMap<'a> {
+    pub fn new(
+        payload_type: u8,
+        encoding_name: &'a str,
+        clock_rate: u32,
+        channels: Option<u8>,
+    ) -> Self {
+        Self {
+            payload_type,
+            encoding_name,
+            clock_rate,
+            channels,
+        }
+    }
+
+    pub fn payload_type(&self) -> u8 {
+        self.payload_type
+    }
+
+    pub fn encoding_name(&self) -> &'a str {
+        self.encoding_name
+    }
+
+    /// Returns the clock rate, in Hz.
+    pub fn clock_rate(&self) -> u32 {
+        self.clock_rate
+    }
+
+    /// Returns the number of audio channels, if given as encoding parameter.
+    pub fn channels(&self) -> Option<u8> {
+        self.channels
+    }
+}
+
+impl Rebind for RtpMap<'_> {
+    type Output<'b> = RtpMap<'b>;
+
+    fn rebind<'b>(&self, strings: &mut dyn FnMut(&str) -> &'b str) -> RtpMap<'b> {
+        RtpMap {
+            payload_type: self.payload_type,
+            encoding_name: strings(self.encoding_name),
+            clock_rate: self.clock_rate,
+            channels: self.channels,
+        }
+    }
+}
+
+impl fmt::Display for RtpMap<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {}/{}",
+            self.payload_type, self.encoding_name, self.clock_rate
+        )?;
+        if let Some(channels) = self.channels {
+            write!(f, "/{}", channels)?;
+        }
+        Ok(())
+    }
+}
+
+/// a=rtpmap:<payload type> <encoding name>/<clock rate>[/<encoding parameters>]
+/// a=rtpmap:96 opus/48000/2
+/// see https://tools.ietf.org/html/rfc8866#section-6.6
+pub fn parse_rtpmap<'i, E: ParseError<&'i str>>(input: &'i str) -> IResult<&'i str, RtpMap<'i>, E> {
+    let (tail, payload_type) = terminated(u8, space1).parse(input)?;
+    let (tail, encoding_name) =
+        terminated(take_till1(|c: char| c == '/'), char('/')).parse(tail)?;
+    let (tail, clock_rate) = u32(tail)?;
+    let (tail, channels) = opt(preceded(char('/'), u8)).parse(tail)?;
+    Ok((
+        tail,
+        RtpMap::new(payload_type, encoding_name, clock_rate, channels),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_rtpmap() {
+        let (tail, value) = parse_rtpmap::<()>("96 opus/48000/2").unwrap();
+        assert_eq!(tail, "");
+        assert_eq!(value.payload_type, 96);
+        assert_eq!(value.encoding_name, "opus");
+        assert_eq!(value.clock_rate, 48000);
+        assert_eq!(value.channels, Some(2));
+        assert_eq!(value.to_string(), "96 opus/48000/2");
+    }
+
+    #[test]
+    fn test_parse_rtpmap_without_channels() {
+        let (tail, value) = parse_rtpmap::<()>("99 h263-1998/90000").unwrap();
+        assert_eq!(tail, "");
+        assert_eq!(value, RtpMap::new(99, "h263-1998", 90000, None));
+    }
+
+    #[test]
+    fn test_parse_rtpmap_invalid_payload_type() {
+        assert!(parse_rtpmap::<()>("256 opus/48000").is_err());
+        assert!(parse_rtpmap::<()>("96 opus").is_err());
+    }
+}
@@ -0,0 +1,87 @@
+use std::fmt;
+
+use nom::{branch::alt, bytes::complete::tag, combinator::map, error::ParseError, IResult, Parser};
+
+/// The value of an `a=setup:` attribute, the role of an endpoint in a DTLS/TCP connection.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Setup {
+    /// The endpoint will initiate an outgoing connection.
+    Active,
+    /// The endpoint will accept an incoming connection.
+    Passive,
+    /// The endpoint is willing to accept an incoming connection or to initiate an outgoing one.
+    ActPass,
+    /// The endpoint does not want the connection to be established for the time being.
+    HoldConn,
+}
+
+impl Setup {
+    /// Returns the role an answerer takes in reply to this offered role.
+    ///
+    /// An `actpass` offer is answered with `active`, as recommended by RFC 5763.
+    pub fn answer(&self) -> Setup {
+        match self {
+            Setup::Active => Setup::Passive,
+            Setup::Passive | Setup::ActPass => Setup::Active,
+            Setup::HoldConn => Setup::HoldConn,
+        }
+    }
+}
+
+impl fmt::Display for Setup {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Setup::Active => write!(f, "active"),
+            Setup::Passive => write!(f, "passive"),
+            Setup::ActPass => write!(f, "actpass"),
+            Setup::HoldConn => write!(f, "holdconn"),
+        }
+    }
+}
+
+/// a=setup:<role>
+/// a=setup:actpass
+/// see https://tools.ietf.org/html/rfc4145#section-4
+pub fn parse_setup<'i, E: ParseError<&'i str>>(input: &'i str) -> IResult<&'i str, Setup, E> {
+    alt((
+        map(tag("actpass"), |_| Setup::ActPass),
+        map(tag("active"), |_| Setup::Active),
+        map(tag("passive"), |_| Setup::Passive),
+        map(tag("holdconn"), |_| Setup::HoldConn),
+    ))
+    .parse(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_setup() {
+        for (input, expected) in [
+            ("actpass", Setup::ActPass),
+            ("active", Setup::Active),
+            ("passive", Setup::Passive),
+            ("holdconn", Setup::HoldConn),
+        ] {
+            let (tail, value) = parse_setup::<()>(input).unwrap();
+            assert_eq!(tail, "");
+            assert_eq!(value, expected);
+            assert_eq!(value.to_string(), input);
+        }
+    }
+
+    #[test]
+    fn test_parse_setup_unknown() {
+        assert!(parse_setup::<()>("sometimes").is_err());
+    }
+
+    #[test]
+    fn test_setup_answer() {
+        assert_eq!(Setup::ActPass.answer(), Setup::Active);
+        assert_eq!(Setup::Active.answer(), Setup::Passive);
+        assert_eq!(Setup::Passive.answer(), Setup::Active);
+        assert_eq!(Setup::HoldConn.answer(), Setup::HoldConn);
+    }
+}
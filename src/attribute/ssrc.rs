@@ -0,0 +1,181 @@
+use std::fmt;
+
+use nom::{
+    bytes::complete::take_till1,
+    character::complete::{char, space1, u32},
+    combinator::{opt, rest},
+    error::ParseError,
+    multi::many0,
+    sequence::preceded,
+    IResult, Parser,
+};
+
+use super::parse_token;
+use crate::session_desription::Rebind;
+
+/// The value of an `a=ssrc:` attribute, one source-level attribute of an RTP source.
+///
+/// RFC 5576 carries a single `<attribute>[:<value>]` per line, so a source with several
+/// attributes (`cname`, `msid`, ...) appears as several `a=ssrc:` lines sharing the same id.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Ssrc<'a> {
+    id: u32,
+    attribute: &'a str,
+    value: Option<&'a str>,
+}
+
+impl<'a> Ssrc<'a> {
+    pub fn new(id: u32, attribute: &'a str, value: Option<&'a str>) -> Self {
+        Self {
+            id,
+            attribute,
+            value,
+        }
+    }
+
+    /// Returns the synchronization source identifier.
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    /// Returns the source-level attribute name, e.g. `cname`.
+    pub fn attribute(&self) -> &'a str {
+        self.attribute
+    }
+
+    /// Returns the source-level attribute value, e.g. `user@host` for `cname:user@host`.
+    pub fn value(&self) -> Option<&'a str> {
+        self.value
+    }
+}
+
+impl Rebind for Ssrc<'_> {
+    type Output<'b> = Ssrc<'b>;
+
+    fn rebind<'b>(&self, strings: &mut dyn FnMut(&str) -> &'b str) -> Ssrc<'b> {
+        Ssrc {
+            id: self.id,
+            attribute: strings(self.attribute),
+            value: self.value.rebind(strings),
+        }
+    }
+}
+
+impl fmt::Display for Ssrc<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.id, self.attribute)?;
+        if let Some(value) = self.value {
+            write!(f, ":{}", value)?;
+        }
+        Ok(())
+    }
+}
+
+/// The value of an `a=ssrc-group:` attribute, relating several RTP sources, e.g. a media
+/// stream and its retransmission (`FID`) stream.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SsrcGroup<'a> {
+    semantics: &'a str,
+    ssrcs: Vec<u32>,
+}
+
+impl<'a> SsrcGroup<'a> {
+    pub fn new(semantics: &'a str, ssrcs: Vec<u32>) -> Self {
+        Self { semantics, ssrcs }
+    }
+
+    /// Returns the grouping semantics, e.g. `FID` or `SIM`.
+    pub fn semantics(&self) -> &'a str {
+        self.semantics
+    }
+
+    /// Returns the identifiers of the grouped sources, in order.
+    pub fn ssrcs(&self) -> &[u32] {
+        &self.ssrcs
+    }
+}
+
+impl Rebind for SsrcGroup<'_> {
+    type Output<'b> = SsrcGroup<'b>;
+
+    fn rebind<'b>(&self, strings: &mut dyn FnMut(&str) -> &'b str) -> SsrcGroup<'b> {
+        SsrcGroup {
+            semantics: strings(self.semantics),
+            ssrcs: self.ssrcs.clone(),
+        }
+    }
+}
+
+impl fmt::Display for SsrcGroup<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.semantics)?;
+        for ssrc in &self.ssrcs {
+            write!(f, " {}", ssrc)?;
+        }
+        Ok(())
+    }
+}
+
+/// a=ssrc:<ssrc-id> <attribute>:<value>
+/// a=ssrc:1234567890 cname:user@host
+/// see https://tools.ietf.org/html/rfc5576#section-4.1
+pub fn parse_ssrc<'i, E: ParseError<&'i str>>(input: &'i str) -> IResult<&'i str, Ssrc<'i>, E> {
+    let (tail, id) = u32(input)?;
+    let (tail, attribute) =
+        preceded(space1, take_till1(|c: char| c == ':' || c.is_whitespace())).parse(tail)?;
+    let (tail, value) = opt(preceded(char(':'), rest)).parse(tail)?;
+    Ok((tail, Ssrc::new(id, attribute, value)))
+}
+
+/// a=ssrc-group:<semantics> *(<ssrc-id>)
+/// a=ssrc-group:FID 1234 5678
+/// see https://tools.ietf.org/html/rfc5576#section-4.2
+pub fn parse_ssrc_group<'i, E: ParseError<&'i str>>(
+    input: &'i str,
+) -> IResult<&'i str, SsrcGroup<'i>, E> {
+    let (tail, semantics) = parse_token(input)?;
+    let (tail, ssrcs) = many0(preceded(space1, u32)).parse(tail)?;
+    Ok((tail, SsrcGroup::new(semantics, ssrcs)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_ssrc() {
+        let input = "1234567890 cname:user@host";
+        let (tail, value) = parse_ssrc::<()>(input).unwrap();
+        assert_eq!(tail, "");
+        assert_eq!(value.id, 1234567890);
+        assert_eq!(value.attribute, "cname");
+        assert_eq!(value.value, Some("user@host"));
+        assert_eq!(value.to_string(), input);
+    }
+
+    #[test]
+    fn test_parse_ssrc_value_with_spaces() {
+        let (_, value) = parse_ssrc::<()>("1234 msid:stream track").unwrap();
+        assert_eq!(value, Ssrc::new(1234, "msid", Some("stream track")));
+
+        let (_, value) = parse_ssrc::<()>("1234 flag").unwrap();
+        assert_eq!(value, Ssrc::new(1234, "flag", None));
+    }
+
+    #[test]
+    fn test_parse_ssrc_invalid_id() {
+        assert!(parse_ssrc::<()>("4294967296 cname:x").is_err());
+        assert!(parse_ssrc::<()>("1234").is_err());
+    }
+
+    #[test]
+    fn test_parse_ssrc_group() {
+        let input = "FID 1234 5678";
+        let (tail, value) = parse_ssrc_group::<()>(input).unwrap();
+        assert_eq!(tail, "");
+        assert_eq!(value, SsrcGroup::new("FID", vec![1234, 5678]));
+        assert_eq!(value.to_string(), input);
+    }
+}
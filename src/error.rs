@@ -0,0 +1,210 @@
+use std::fmt;
+
+use nom::error::Error;
+
+/// Errors returned while parsing an SDP session description.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SdpError {
+    /// A line that RFC 8866 requires, identified by its `<type>=` prefix, is absent.
+    MissingRequiredLine(&'static str),
+    /// The `<type>=` line could not be parsed. `offset` is the byte offset into the input at
+    /// which parsing failed, `line` the offending line, without its line ending, and
+    /// `rfc_reference` the section defining the line, e.g. `RFC 8866 §5.2`.
+    MalformedLine {
+        line_type: char,
+        offset: usize,
+        line: String,
+        detail: String,
+        rfc_reference: &'static str,
+    },
+    /// The line's `<type>` is not one this crate knows about.
+    UnknownKey(char),
+    /// The `s=` line is empty; RFC 8866 recommends `s= ` or `s=-` for sessions without name.
+    EmptySessionName,
+    /// The `s=` line is not valid in the character set of the session.
+    InvalidSessionName,
+    /// The `a=charset:` attribute names a character set this crate cannot validate.
+    UnsupportedCharset(String),
+    /// The `v=` line holds a version other than `0`, the only one RFC 8866 defines.
+    UnsupportedVersion(u8),
+    /// A line RFC 8866 allows only once, identified by its `<type>`, appears again.
+    DuplicateLine(char),
+    /// A line appears where RFC 8866 does not allow it; `expected` is the `<type>` of the line
+    /// that should have come next (or the earliest one allowed there).
+    OutOfOrderLine { expected: char, found: char },
+    /// An address could not be parsed as an IP address.
+    InvalidAddress,
+    /// The `<addrtype>` of an `o=` or `c=` line does not match its address, e.g. `IP4` with
+    /// an IPv6 literal.
+    AddressTypeMismatch,
+    /// The `<sess-id>` of an `o=` line is not a number.
+    InvalidSessionId,
+    /// The input bytes are not valid UTF-8.
+    InvalidUtf8,
+    /// An `a=group:` line references an identification tag no `m=` section carries with `a=mid:`.
+    UnknownMediaId(String),
+    /// Two `a=extmap:` lines of the same media section share an id.
+    DuplicateExtmapId(u8),
+    /// The media sections of an answer do not match the ones of its offer, starting with the
+    /// one at this index: it is missing, extra, or of another media type.
+    MismatchedMedia(usize),
+}
+
+impl SdpError {
+    /// Builds a [`SdpError::MalformedLine`] out of the nom error raised while parsing `line`,
+    /// a suffix of `input` starting at the offending line.
+    pub(crate) fn malformed(input: &str, line: &str, err: nom::Err<Error<&str>>) -> Self {
+        let line_start = offset_in(input, line).unwrap_or_default();
+        let (offset, detail) = match err {
+            nom::Err::Incomplete(_) => (line_start, "line is incomplete".to_string()),
+            nom::Err::Error(e) | nom::Err::Failure(e) => {
+                let rest = e.input.lines().next().unwrap_or_default();
+                (
+                    offset_in(input, e.input).unwrap_or(line_start),
+                    format!("{} failed at `{}`", e.code.description(), rest),
+                )
+            }
+        };
+        let line_type = line.chars().next().unwrap_or_default();
+        SdpError::MalformedLine {
+            line_type,
+            offset,
+            line: line.lines().next().unwrap_or_default().to_string(),
+            detail,
+            rfc_reference: rfc_reference(line_type),
+        }
+    }
+}
+
+/// Returns the section of RFC 8866 that defines the `<type>=` line, or the one defining the
+/// SDP lines in general for types it does not define.
+pub(crate) fn rfc_reference(line_type: char) -> &'static str {
+    match line_type {
+        'v' => "RFC 8866 §5.1",
+        'o' => "RFC 8866 §5.2",
+        's' => "RFC 8866 §5.3",
+        'i' => "RFC 8866 §5.4",
+        'u' => "RFC 8866 §5.5",
+        'e' | 'p' => "RFC 8866 §5.6",
+        'c' => "RFC 8866 §5.7",
+        'b' => "RFC 8866 §5.8",
+        't' => "RFC 8866 §5.9",
+        'r' => "RFC 8866 §5.10",
+        'z' => "RFC 8866 §5.11",
+        'k' => "RFC 8866 §5.12",
+        'a' => "RFC 8866 §5.13",
+        'm' => "RFC 8866 §5.14",
+        _ => "RFC 8866 §5",
+    }
+}
+
+/// Returns the byte offset of `slice` into `input`, if `slice` points into it.
+pub(crate) fn offset_in(input: &str, slice: &str) -> Option<usize> {
+    (slice.as_ptr() as usize)
+        .checked_sub(input.as_ptr() as usize)
+        .filter(|offset| *offset <= input.len())
+}
+
+impl fmt::Display for SdpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SdpError::MissingRequiredLine(line) => write!(f, "missing required `{}` line", line),
+            SdpError::MalformedLine {
+                line_type,
+                offset,
+                detail,
+                rfc_reference,
+                ..
+            } => write!(
+                f,
+                "malformed `{}=` line at byte {}: {} (see {})",
+                line_type, offset, detail, rfc_reference
+            ),
+            SdpError::UnknownKey(key) => write!(f, "unknown line type `{}=`", key),
+            SdpError::EmptySessionName => write!(f, "session name is empty"),
+            SdpError::InvalidSessionName => {
+                write!(f, "session name is not valid in the session charset")
+            }
+            SdpError::UnsupportedCharset(charset) => {
+                write!(f, "unsupported charset `{}`", charset)
+            }
+            SdpError::UnsupportedVersion(version) => {
+                write!(f, "unsupported protocol version `{}`", version)
+            }
+            SdpError::DuplicateLine(line_type) => write!(f, "duplicate `{}=` line", line_type),
+            SdpError::OutOfOrderLine { expected, found } => write!(
+                f,
+                "`{}=` line is out of order, expected `{}=`",
+                found, expected
+            ),
+            SdpError::InvalidAddress => write!(f, "invalid address"),
+            SdpError::AddressTypeMismatch => write!(f, "address does not match its address type"),
+            SdpError::InvalidSessionId => write!(f, "session id is not a number"),
+            SdpError::InvalidUtf8 => write!(f, "input is not valid UTF-8"),
+            SdpError::UnknownMediaId(mid) => {
+                write!(f, "group references unknown media id `{}`", mid)
+            }
+            SdpError::DuplicateExtmapId(id) => write!(f, "duplicate extmap id `{}`", id),
+            SdpError::MismatchedMedia(index) => {
+                write!(
+                    f,
+                    "media section {} does not match between offer and answer",
+                    index
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for SdpError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_malformed_detail() {
+        let input = "v=0\r\nv=abc\r\ns=x\r\n";
+        let err = nom::Err::Error(Error::new(&input[7..], nom::error::ErrorKind::Digit));
+        assert_eq!(
+            SdpError::malformed(input, &input[5..], err),
+            SdpError::MalformedLine {
+                line_type: 'v',
+                offset: 7,
+                line: "v=abc".to_string(),
+                detail: "Digit failed at `abc`".to_string(),
+                rfc_reference: "RFC 8866 §5.1",
+            }
+        );
+    }
+
+    #[test]
+    fn test_rfc_reference() {
+        assert_eq!(rfc_reference('o'), "RFC 8866 §5.2");
+        assert_eq!(rfc_reference('z'), "RFC 8866 §5.11");
+        assert_eq!(rfc_reference('m'), "RFC 8866 §5.14");
+        assert_eq!(rfc_reference('x'), "RFC 8866 §5");
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            SdpError::MissingRequiredLine("s=").to_string(),
+            "missing required `s=` line"
+        );
+        assert_eq!(
+            SdpError::UnknownKey('x').to_string(),
+            "unknown line type `x=`"
+        );
+        let input = "v=x\r\n";
+        let err = nom::Err::Error(Error::new(&input[2..], nom::error::ErrorKind::Digit));
+        assert_eq!(
+            SdpError::malformed(input, input, err).to_string(),
+            "malformed `v=` line at byte 2: Digit failed at `x` (see RFC 8866 §5.1)"
+        );
+        assert_eq!(
+            SdpError::UnsupportedVersion(9).to_string(),
+            "unsupported protocol version `9`"
+        );
+    }
+}
@@ -1,50 +1,205 @@
+mod attribute;
+mod error;
+mod media_description;
+mod options;
 mod session_desription;
+mod time_description;
+pub use attribute::{
+    Attribute, AttributeList, Candidate, CandidateType, Direction, ExtMap, Fingerprint, Fmtp,
+    Framerate, Group, ImageAttr, ImageAttrSet, MediaClk, Msid, MsidSemantic, Rid, RidDirection,
+    RtpMap, Setup, Simulcast, Ssrc, SsrcGroup, TsRefClk,
+};
+pub use error::SdpError;
+pub use media_description::{MediaDescription, MediaProto};
+pub use options::ParseOptions;
+pub use session_desription::{
+    AddrType, Bandwidth, ConnectionAddress, ConnectionInformation, Email, EncryptionKey, NetType,
+    Origin, OriginBuilder, OwnedSessionDescription, Phone, Role, SdpStreamParser,
+    SessionDescription, SessionInformation, SessionName, Uri, Version,
+};
+pub use time_description::{RepeatTimes, TimeDescription, TimeZone};
 // mod utils;
 pub fn add(left: usize, right: usize) -> usize {
     left + right
 }
 
+/// Parses an SDP session description, strictly following RFC 8866 (see
+/// [`ParseOptions::strict`]).
+///
+/// The returned [`SessionDescription`] borrows all of its text from `input` rather than
+/// copying it, see [`SessionDescription`] for details.
+///
+/// A UTF-8 byte order mark and blank lines before the `v=` line are skipped.
+///
+/// # Example
+///
+/// ```
+/// let input = "v=0\r\no=jdoe 2890844526 2890842807 IN IP4 192.168.10.1\r\ns=SDP Seminar\r\nt=0 0\r\n";
+/// let session = sdp_parser::parse(input).unwrap();
+/// assert_eq!(session.session_name().name(), "SDP Seminar");
+/// ```
+pub fn parse(input: &str) -> Result<SessionDescription<'_>, SdpError> {
+    SessionDescription::from_str(input)
+}
+
+/// Parses an SDP session description from raw bytes, e.g. straight out of a network buffer.
+///
+/// The bytes are validated as UTF-8 once and then parsed in place, without being copied.
+///
+/// # Example
+///
+/// ```
+/// let input = b"v=0\r\no=- 1 1 IN IP4 10.0.0.1\r\ns=-\r\nt=0 0\r\n";
+/// let session = sdp_parser::parse_bytes(input).unwrap();
+/// assert_eq!(session.origin().username(), "-");
+/// ```
+pub fn parse_bytes(input: &[u8]) -> Result<SessionDescription<'_>, SdpError> {
+    let input = std::str::from_utf8(input).map_err(|_| SdpError::InvalidUtf8)?;
+    parse(input)
+}
+
+/// Parses an SDP session description at the start of `input`, returning it along with the
+/// input that follows it.
+///
+/// The description ends right before the first line that is not of the form
+/// `<type>=<value>`, e.g. the blank line or boundary that follows an SDP body embedded in a
+/// larger message.
+///
+/// # Example
+///
+/// ```
+/// let input = "v=0\r\no=- 1 1 IN IP4 10.0.0.1\r\ns=-\r\nt=0 0\r\n\r\n--boundary--\r\n";
+/// let (session, tail) = sdp_parser::parse_partial(input).unwrap();
+/// assert_eq!(session.origin().username(), "-");
+/// assert_eq!(tail, "\r\n--boundary--\r\n");
+/// ```
+pub fn parse_partial(input: &str) -> Result<(SessionDescription<'_>, &str), SdpError> {
+    let (sdp, tail) = split_after_description(skip_preamble(input));
+    Ok((parse(sdp)?, tail))
+}
+
+/// Splits `input` into its `<type>=<value>` lines, without parsing the values.
+///
+/// Lines end with `\r\n` or `\n`; a line that does not start with a letter followed by `=`,
+/// including an empty one, yields [`SdpError::MalformedLine`] and the iteration goes on with
+/// the next line.
+///
+/// # Example
+///
+/// ```
+/// let input = "v=0\r\ns=Seminar\na=recvonly\r\n";
+/// let lines: Vec<_> = sdp_parser::lines(input).collect::<Result<_, _>>().unwrap();
+/// assert_eq!(lines, [('v', "0"), ('s', "Seminar"), ('a', "recvonly")]);
+/// ```
+pub fn lines(input: &str) -> impl Iterator<Item = Result<(char, &str), SdpError>> + '_ {
+    let mut offset = 0;
+    input.split_inclusive('\n').map(move |line| {
+        let start = offset;
+        offset += line.len();
+        split_line(line.trim_end_matches('\n').trim_end_matches('\r'), start)
+    })
+}
+
+/// Splits a `line` found at `offset` into its `<type>` and `<value>`.
+pub(crate) fn split_line(line: &str, offset: usize) -> Result<(char, &str), SdpError> {
+    let mut chars = line.chars();
+    match (chars.next(), chars.next()) {
+        (Some(line_type), Some('=')) if line_type.is_ascii_alphabetic() => {
+            Ok((line_type, &line[2..]))
+        }
+        (line_type, _) => Err(SdpError::MalformedLine {
+            line_type: line_type.unwrap_or_default(),
+            offset,
+            line: line.to_string(),
+            detail: "expected a `<type>=<value>` line".to_string(),
+            // not a `<type>=` line at all, so no section of a line type applies
+            rfc_reference: "RFC 8866 §5",
+        }),
+    }
+}
+
+/// Skips what some stacks send before the `v=` line: a UTF-8 byte order mark, then
+/// whitespace and blank lines. Nothing is skipped past the first other character.
+pub(crate) fn skip_preamble(input: &str) -> &str {
+    input.strip_prefix('\u{feff}').unwrap_or(input).trim_start()
+}
+
+/// Parses an SDP session description with the given [`ParseOptions`].
+///
+/// # Example
+///
+/// ```
+/// use sdp_parser::{EncryptionKey, ParseOptions};
+///
+/// let input = "v=0\r\no=- 1 1 IN IP4 10.0.0.1\r\ns=-\r\nt=0 0\r\nk=prompt\r\n";
+/// let options = ParseOptions {
+///     keep_encryption_key: true,
+///     ..ParseOptions::default()
+/// };
+/// let session = sdp_parser::parse_with_options(input, &options).unwrap();
+/// assert_eq!(session.encryption_key(), Some(&EncryptionKey::Prompt));
+/// ```
+pub fn parse_with_options<'a>(
+    input: &'a str,
+    options: &ParseOptions,
+) -> Result<SessionDescription<'a>, SdpError> {
+    SessionDescription::from_str_with_options(input, options)
+}
+
 // An SDP description consists of a number of lines of text of the form:
 //    <type>=<value>
 
+#[allow(clippy::enum_variant_names)]
 enum SDPLevel {
     SessionLevel,
     TimeDescriptionLevel,
     MediaDescriptionLevel,
 }
 
-impl SDPLevel {
-    fn from_str(s: &str) -> Result<Self, ()> {
-        match s {
-            "s" => Ok(SDPLevel::SessionLevel),
-            "t" => Ok(SDPLevel::TimeDescriptionLevel),
-            "m" => Ok(SDPLevel::MediaDescriptionLevel),
-            _ => Err(()),
+/// Splits `input` right before the first line starting with one of the `<type>=` `keys`,
+/// ignoring the first line if `skip_first` is set. Returns `(input, "")` if there is none.
+fn split_before_line<'a>(input: &'a str, keys: &[char], skip_first: bool) -> (&'a str, &'a str) {
+    let mut offset = 0;
+    for line in input.split_inclusive('\n') {
+        let is_boundary =
+            line.as_bytes().get(1) == Some(&b'=') && keys.iter().any(|&key| line.starts_with(key));
+        if is_boundary && !(skip_first && offset == 0) {
+            return input.split_at(offset);
         }
+        offset += line.len();
     }
+    (input, "")
+}
 
-    fn as_str(&self) -> &str {
-        match self {
-            SDPLevel::SessionLevel => "s",
-            SDPLevel::TimeDescriptionLevel => "t",
-            SDPLevel::MediaDescriptionLevel => "m",
+/// Splits `input` right before the first line that is not a `<type>=<value>` line.
+fn split_after_description(input: &str) -> (&str, &str) {
+    let mut offset = 0;
+    for line in input.split_inclusive('\n') {
+        let bytes = line.as_bytes();
+        if !(bytes.first().is_some_and(u8::is_ascii_alphabetic) && bytes.get(1) == Some(&b'=')) {
+            return input.split_at(offset);
         }
+        offset += line.len();
     }
+    (input, "")
 }
 
-fn get_session_description_chunk() {
-    // get till the next time description
-    unimplemented!("get_session_description_chunk");
+/// Returns the session-level chunk of `input` and the remaining tail.
+fn get_session_description_chunk(input: &str) -> (&str, &str) {
+    // get till the next time description (or media description if `t=` is missing)
+    split_before_line(input, &['t', 'm'], false)
 }
 
-fn get_time_description_chunk() {
+/// Returns the time description chunk of `input` and the remaining tail.
+fn get_time_description_chunk(input: &str) -> (&str, &str) {
     // get till the next media description
-    unimplemented!("get_time_description_chunk");
+    split_before_line(input, &['m'], false)
 }
 
-fn get_media_description_chunk() {
+/// Returns the media description chunk at the start of `input` and the remaining tail.
+fn get_media_description_chunk(input: &str) -> (&str, &str) {
     // get till the next media description
-    unimplemented!("get_media_description_chunk");
+    split_before_line(input, &['m'], true)
 }
 
 #[cfg(test)]
@@ -56,4 +211,116 @@ mod tests {
         let result = add(2, 2);
         assert_eq!(result, 4);
     }
+
+    #[test]
+    fn test_parse() {
+        let input =
+            "v=0\r\no=jdoe 2890844526 2890842807 IN IP4 192.168.10.1\r\ns=SDP Seminar\r\nt=0 0\r\n";
+        let session = parse(input).unwrap();
+        assert_eq!(session.version(), &Version::new(0));
+        assert_eq!(session.origin().username(), "jdoe");
+        assert_eq!(session.session_name(), &SessionName::new("SDP Seminar"));
+    }
+
+    #[test]
+    fn test_parse_skips_preamble() {
+        let sdp = "v=0\r\no=- 1 1 IN IP4 10.0.0.1\r\ns=-\r\nt=0 0\r\n";
+        for preamble in ["\u{feff}", "\r\n\r\n", " \t", "\u{feff}\r\n  \n"] {
+            let input = format!("{}{}", preamble, sdp);
+            let session = parse(&input).unwrap();
+            assert_eq!(session.to_string(), sdp, "{:?}", preamble);
+            let (session, tail) = parse_partial(&input).unwrap();
+            assert_eq!(session.origin().username(), "-");
+            assert_eq!(tail, "");
+        }
+
+        let err = parse("\u{feff}\r\nv=0\r\no=- x 1 IN IP4 10.0.0.1\r\ns=-\r\nt=0 0\r\n");
+        assert!(matches!(
+            err,
+            Err(SdpError::MalformedLine { offset: 14, .. })
+        ));
+        assert!(parse("x\u{feff}v=0\r\no=- 1 1 IN IP4 10.0.0.1\r\ns=-\r\nt=0 0\r\n").is_err());
+    }
+
+    #[test]
+    fn test_get_description_chunks() {
+        let input = "v=0\r\ns=-\r\nt=0 0\r\na=tool:foo\r\nm=audio 9 RTP/AVP 0\r\na=mid:0\r\nm=video 9 RTP/AVP 96\r\n";
+        let (session, tail) = get_session_description_chunk(input);
+        assert_eq!(session, "v=0\r\ns=-\r\n");
+        let (time, tail) = get_time_description_chunk(tail);
+        assert_eq!(time, "t=0 0\r\na=tool:foo\r\n");
+        let (audio, tail) = get_media_description_chunk(tail);
+        assert_eq!(audio, "m=audio 9 RTP/AVP 0\r\na=mid:0\r\n");
+        let (video, tail) = get_media_description_chunk(tail);
+        assert_eq!(video, "m=video 9 RTP/AVP 96\r\n");
+        assert_eq!(tail, "");
+    }
+
+    #[test]
+    fn test_get_chunk_ignores_values() {
+        let (session, tail) = get_session_description_chunk("v=0\r\ns=t=0\r\n");
+        assert_eq!(session, "v=0\r\ns=t=0\r\n");
+        assert_eq!(tail, "");
+    }
+
+    #[test]
+    fn test_parse_partial() {
+        let input = "v=0\r\no=- 1 1 IN IP4 127.0.0.1\r\ns=-\r\nt=0 0\r\nm=audio 9 RTP/AVP 0\r\na=rtcp-mux\r\nContent-Type: text/plain\r\n";
+        let (session, tail) = parse_partial(input).unwrap();
+        assert_eq!(session.media().len(), 1);
+        assert!(session.media()[0].rtcp_mux());
+        assert_eq!(tail, "Content-Type: text/plain\r\n");
+
+        let input = "v=0\r\no=- 1 1 IN IP4 127.0.0.1\r\ns=-\r\nt=0 0";
+        let (_, tail) = parse_partial(input).unwrap();
+        assert_eq!(tail, "");
+
+        let input = "v=0\r\n\r\no=- 1 1 IN IP4 127.0.0.1\r\ns=-\r\nt=0 0\r\n";
+        assert_eq!(
+            parse_partial(input).unwrap_err(),
+            SdpError::MissingRequiredLine("o=")
+        );
+    }
+
+    #[test]
+    fn test_lines() {
+        let input = "v=0\r\no=- 1 1 IN IP4 127.0.0.1\ns=\r\nbogus\r\n\r\nt=0 0";
+        let lines: Vec<_> = lines(input).collect();
+        assert_eq!(lines[0], Ok(('v', "0")));
+        assert_eq!(lines[1], Ok(('o', "- 1 1 IN IP4 127.0.0.1")));
+        assert_eq!(lines[2], Ok(('s', "")));
+        assert!(matches!(
+            &lines[3],
+            Err(SdpError::MalformedLine { line_type: 'b', offset: 34, line, .. }) if line == "bogus"
+        ));
+        assert!(matches!(
+            &lines[4],
+            Err(SdpError::MalformedLine { offset: 41, .. })
+        ));
+        assert_eq!(lines[5], Ok(('t', "0 0")));
+        assert_eq!(lines.len(), 6);
+        assert_eq!(super::lines("").count(), 0);
+    }
+
+    #[test]
+    fn test_parse_bytes() {
+        let input = b"v=0\r\no=jdoe 2890844526 2890842807 IN IP4 192.168.10.1\r\ns=SDP Seminar\r\nt=0 0\r\n";
+        let session = parse_bytes(input).unwrap();
+        assert_eq!(session.session_name(), &SessionName::new("SDP Seminar"));
+    }
+
+    #[test]
+    fn test_parse_bytes_invalid_utf8() {
+        let input = b"v=0\r\ns=\xff\xfe\r\n";
+        assert_eq!(parse_bytes(input).unwrap_err(), SdpError::InvalidUtf8);
+    }
+
+    #[test]
+    fn test_parse_malformed_line() {
+        let result = parse("v=0\r\n-=\r\n");
+        assert!(matches!(
+            result.unwrap_err(),
+            SdpError::MalformedLine { line_type: '-', .. }
+        ));
+    }
 }
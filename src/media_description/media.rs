@@ -0,0 +1,764 @@
+use std::{borrow::Cow, fmt};
+
+use nom::{
+    bytes::complete::{tag, take_till1},
+    character::complete::{char, line_ending, space1, u16},
+    combinator::opt,
+    error::{FromExternalError, ParseError},
+    multi::many1,
+    sequence::{preceded, terminated},
+    IResult, Parser,
+};
+
+use super::MediaProto;
+use crate::attribute::{
+    Attribute, AttributeList, Candidate, Direction, ExtMap, Fingerprint, Fmtp, ImageAttr, MediaClk,
+    Msid, Rid, RtpMap, Setup, Simulcast, Ssrc, SsrcGroup, TsRefClk,
+};
+use crate::session_desription::Rebind;
+use crate::session_desription::{
+    Bandwidth, ConnectionInformation, EncryptionKey, SessionDescription, SessionInformation,
+    VerbatimLines,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MediaDescription<'a> {
+    media: &'a str,
+    port: u16,
+    port_count: Option<u16>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    proto: MediaProto<'a>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    formats: Vec<&'a str>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    information: Option<SessionInformation<'a>>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    connections: Vec<ConnectionInformation<'a>>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    bandwidths: Vec<Bandwidth<'a>>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    encryption_key: Option<EncryptionKey<'a>>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    attributes: AttributeList<'a>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    verbatim_lines: VerbatimLines<'a>,
+}
+
+impl<'a> MediaDescription<'a> {
+    pub fn new(
+        media: &'a str,
+        port: u16,
+        port_count: Option<u16>,
+        proto: &'a str,
+        formats: Vec<&'a str>,
+    ) -> Self {
+        Self {
+            media,
+            port,
+            port_count,
+            proto: MediaProto::from(proto),
+            formats,
+            information: None,
+            connections: Vec::new(),
+            bandwidths: Vec::new(),
+            encryption_key: None,
+            attributes: AttributeList::new(),
+            verbatim_lines: VerbatimLines::default(),
+        }
+    }
+
+    /// Returns the media type, e.g. `audio`, `video`, `text` or `application`.
+    pub fn media(&self) -> &'a str {
+        self.media
+    }
+
+    pub fn port(&self) -> u16 {
+        self.port
+    }
+
+    /// Rejects the media section, as an answerer does for a media stream it does not accept
+    /// (RFC 3264 §6).
+    ///
+    /// The port is set to `0` and the bandwidths, key and attributes are dropped, except for the
+    /// `a=mid:` that identifies the section. The section itself stays in place, since media
+    /// sections are matched between offer and answer by their position.
+    pub fn reject(&mut self) {
+        self.verbatim_lines.clear();
+        self.port = 0;
+        self.port_count = None;
+        self.bandwidths.clear();
+        self.encryption_key = None;
+        self.attributes
+            .retain(|attribute| matches!(attribute, Attribute::MediaId(_)));
+    }
+
+    /// Returns `true` if the media section is rejected or disabled, i.e. its port is `0`.
+    pub fn is_rejected(&self) -> bool {
+        self.port == 0
+    }
+
+    /// Returns the number of ports given in the `<port>/<number of ports>` form.
+    pub fn port_count(&self) -> Option<u16> {
+        self.port_count
+    }
+
+    /// Returns the transport protocol, e.g. `RTP/AVP` or `UDP/TLS/RTP/SAVPF`.
+    pub fn proto(&self) -> &'a str {
+        self.proto.as_str()
+    }
+
+    /// Returns the transport protocol as a [`MediaProto`], e.g. to tell RTP from SCTP.
+    pub fn media_proto(&self) -> MediaProto<'a> {
+        self.proto
+    }
+
+    /// Returns the media formats, e.g. RTP payload types for the RTP profiles.
+    pub fn formats(&self) -> &[&'a str] {
+        &self.formats
+    }
+
+    /// Returns the media title (`i=`), if present.
+    pub fn information(&self) -> Option<&SessionInformation<'a>> {
+        self.information.as_ref()
+    }
+
+    /// Returns the first media-level connection data (`c=`), if present.
+    pub fn connection(&self) -> Option<&ConnectionInformation<'a>> {
+        self.connections.first()
+    }
+
+    /// Returns the media-level connection data (`c=`), in the order they appeared. A media
+    /// section may have several, e.g. one per layer of a layered multicast session.
+    ///
+    /// see https://tools.ietf.org/html/rfc8866#section-5.7
+    pub fn connections(&self) -> &[ConnectionInformation<'a>] {
+        &self.connections
+    }
+
+    /// Returns the media-level bandwidth lines (`b=`), in the order they appeared.
+    pub fn bandwidths(&self) -> &[Bandwidth<'a>] {
+        &self.bandwidths
+    }
+
+    /// Returns the media-level encryption key (`k=`), if present and kept, see
+    /// [`ParseOptions::keep_encryption_key`](crate::ParseOptions::keep_encryption_key).
+    pub fn encryption_key(&self) -> Option<&EncryptionKey<'a>> {
+        self.encryption_key.as_ref()
+    }
+
+    /// Returns the media-level attributes (`a=`), in the order they appeared.
+    pub fn attributes(&self) -> &[Attribute<'a>] {
+        &self.attributes
+    }
+
+    /// Returns the media-level attributes for modification.
+    pub fn attributes_mut(&mut self) -> &mut AttributeList<'a> {
+        &mut self.attributes
+    }
+
+    /// Returns the attributes of the media named `name`, e.g. every `a=candidate:` line for
+    /// `candidate`, in the order they appeared.
+    pub fn attributes_by_name<'s>(
+        &'s self,
+        name: &'s str,
+    ) -> impl Iterator<Item = &'s Attribute<'a>> + 's {
+        self.attributes
+            .iter()
+            .filter(move |attribute| attribute.name() == name)
+    }
+
+    /// Returns the value of the first `a=<name>:<value>` attribute of the media as a number,
+    /// e.g. `29.97` for `a=framerate:29.97`, or `None` if it is absent or not a number.
+    ///
+    /// Typed attributes are found by name too, e.g. `a=ptime:20` through `"ptime"`.
+    pub fn attribute_f64(&self, name: &str) -> Option<f64> {
+        self.attribute_value(name)?.trim().parse().ok()
+    }
+
+    /// Like [`MediaDescription::attribute_f64`], for integer values such as `a=quality:10`.
+    pub fn attribute_u32(&self, name: &str) -> Option<u32> {
+        self.attribute_value(name)?.trim().parse().ok()
+    }
+
+    fn attribute_value(&self, name: &str) -> Option<Cow<'a, str>> {
+        self.attributes_by_name(name)
+            .find_map(Attribute::value_text)
+    }
+
+    /// Returns the `a=rtpmap:` attributes of the media, in the order they appeared.
+    pub fn rtpmaps(&self) -> impl Iterator<Item = &RtpMap<'a>> {
+        self.attributes
+            .iter()
+            .filter_map(|attribute| match attribute {
+                Attribute::RtpMap(rtpmap) => Some(rtpmap),
+                _ => None,
+            })
+    }
+
+    /// Returns the `a=extmap:` attributes of the media, in the order they appeared.
+    pub fn extmaps(&self) -> impl Iterator<Item = &ExtMap<'a>> {
+        self.attributes
+            .iter()
+            .filter_map(|attribute| match attribute {
+                Attribute::ExtMap(extmap) => Some(extmap),
+                _ => None,
+            })
+    }
+
+    /// Returns the ids that more than one `a=extmap:` of the media uses, once each, in the
+    /// order of their second use.
+    ///
+    /// see https://tools.ietf.org/html/rfc8285#section-5
+    pub(crate) fn duplicate_extmap_ids(&self) -> Vec<u8> {
+        let mut seen = Vec::new();
+        let mut duplicates = Vec::new();
+        for id in self.extmaps().map(ExtMap::id) {
+            if seen.contains(&id) {
+                if !duplicates.contains(&id) {
+                    duplicates.push(id);
+                }
+            } else {
+                seen.push(id);
+            }
+        }
+        duplicates
+    }
+
+    /// Returns the `a=ssrc:` attributes of the media, in the order they appeared.
+    pub fn ssrcs(&self) -> impl Iterator<Item = &Ssrc<'a>> {
+        self.attributes
+            .iter()
+            .filter_map(|attribute| match attribute {
+                Attribute::Ssrc(ssrc) => Some(ssrc),
+                _ => None,
+            })
+    }
+
+    /// Returns the `a=ssrc-group:` attributes of the media, in the order they appeared.
+    pub fn ssrc_groups(&self) -> impl Iterator<Item = &SsrcGroup<'a>> {
+        self.attributes
+            .iter()
+            .filter_map(|attribute| match attribute {
+                Attribute::SsrcGroup(group) => Some(group),
+                _ => None,
+            })
+    }
+
+    /// Returns the `a=msid:` attributes of the media, in the order they appeared.
+    pub fn msids(&self) -> impl Iterator<Item = &Msid<'a>> {
+        self.attributes
+            .iter()
+            .filter_map(|attribute| match attribute {
+                Attribute::Msid(msid) => Some(msid),
+                _ => None,
+            })
+    }
+
+    /// Returns the `a=imageattr:` attributes of the media, in the order they appeared.
+    pub fn imageattrs(&self) -> impl Iterator<Item = &ImageAttr<'a>> {
+        self.attributes
+            .iter()
+            .filter_map(|attribute| match attribute {
+                Attribute::ImageAttr(imageattr) => Some(imageattr),
+                _ => None,
+            })
+    }
+
+    /// Returns the `a=rid:` attributes of the media, in the order they appeared.
+    pub fn rids(&self) -> impl Iterator<Item = &Rid<'a>> {
+        self.attributes
+            .iter()
+            .filter_map(|attribute| match attribute {
+                Attribute::Rid(rid) => Some(rid),
+                _ => None,
+            })
+    }
+
+    /// Returns the `a=simulcast:` attribute of the media, if present.
+    pub fn simulcast(&self) -> Option<&Simulcast<'a>> {
+        self.attributes
+            .iter()
+            .find_map(|attribute| match attribute {
+                Attribute::Simulcast(simulcast) => Some(simulcast),
+                _ => None,
+            })
+    }
+
+    /// Returns the `a=fmtp:` attributes of the media, in the order they appeared.
+    pub fn fmtps(&self) -> impl Iterator<Item = &Fmtp<'a>> {
+        self.attributes
+            .iter()
+            .filter_map(|attribute| match attribute {
+                Attribute::Fmtp(fmtp) => Some(fmtp),
+                _ => None,
+            })
+    }
+
+    /// Returns the ICE candidates (`a=candidate:`) of the media, in the order they appeared.
+    pub fn candidates(&self) -> impl Iterator<Item = &Candidate<'a>> {
+        self.attributes
+            .iter()
+            .filter_map(|attribute| match attribute {
+                Attribute::Candidate(candidate) => Some(candidate),
+                _ => None,
+            })
+    }
+
+    /// Returns the ICE candidates of the media from the highest priority to the lowest, the
+    /// order ICE checks them in (RFC 8445 §6.1.2). Candidates of equal priority keep the order
+    /// they appeared in, which [`MediaDescription::candidates`] returns.
+    pub fn candidates_by_priority(&self) -> Vec<&Candidate<'a>> {
+        let mut candidates: Vec<_> = self.candidates().collect();
+        candidates.sort_by_key(|candidate| std::cmp::Reverse(candidate.priority()));
+        candidates
+    }
+
+    /// Returns the media-level DTLS certificate fingerprint (`a=fingerprint:`), if present.
+    pub fn fingerprint(&self) -> Option<&Fingerprint<'a>> {
+        self.attributes
+            .iter()
+            .find_map(|attribute| match attribute {
+                Attribute::Fingerprint(fingerprint) => Some(fingerprint),
+                _ => None,
+            })
+    }
+
+    /// Returns the identifier of the DTLS association of the media (`a=tls-id:`, or the
+    /// older `a=dtls-id:`), if present.
+    ///
+    /// see https://tools.ietf.org/html/rfc8842#section-5
+    pub fn tls_id(&self) -> Option<&'a str> {
+        self.attributes
+            .iter()
+            .find_map(|attribute| match attribute {
+                Attribute::TlsId(id) => Some(*id),
+                _ => None,
+            })
+            .or_else(|| {
+                self.attributes
+                    .iter()
+                    .find_map(|attribute| match attribute {
+                        Attribute::DtlsId(id) => Some(*id),
+                        _ => None,
+                    })
+            })
+    }
+
+    /// Returns the tls-id of the media if set, otherwise the session-level one of `session`.
+    pub fn effective_tls_id(&self, session: &SessionDescription<'a>) -> Option<&'a str> {
+        self.tls_id().or_else(|| session.tls_id())
+    }
+
+    /// Returns `true` if this media section replaces the DTLS association `previous` used,
+    /// i.e. a new DTLS handshake is needed.
+    ///
+    /// The media-level tls-ids are compared as opaque strings. A section that carries none
+    /// can not signal a new association, so only a different tls-id on both sides counts as
+    /// a change.
+    pub fn tls_id_changed(&self, previous: &MediaDescription<'_>) -> bool {
+        matches!((self.tls_id(), previous.tls_id()), (Some(id), Some(previous)) if id != previous)
+    }
+
+    /// Returns the DTLS/TCP connection role (`a=setup:`) of the media, if present.
+    pub fn setup(&self) -> Option<&Setup> {
+        self.attributes
+            .iter()
+            .find_map(|attribute| match attribute {
+                Attribute::Setup(setup) => Some(setup),
+                _ => None,
+            })
+    }
+
+    /// Returns the connection data that applies to the media: its own first `c=` line if
+    /// present, otherwise the session-level one of `session`.
+    ///
+    /// see https://tools.ietf.org/html/rfc8866#section-5.7
+    pub fn effective_connection<'s>(
+        &'s self,
+        session: &'s SessionDescription<'a>,
+    ) -> Option<&'s ConnectionInformation<'a>> {
+        self.effective_connections(session).first()
+    }
+
+    /// Like [`MediaDescription::effective_connection`], returning every `c=` line of the
+    /// media, or the session-level one if the media has none.
+    pub fn effective_connections<'s>(
+        &'s self,
+        session: &'s SessionDescription<'a>,
+    ) -> &'s [ConnectionInformation<'a>] {
+        match (self.connections.as_slice(), session.connection()) {
+            ([], Some(connection)) => std::slice::from_ref(connection),
+            (connections, _) => connections,
+        }
+    }
+
+    /// Returns the media direction (`a=sendrecv` etc.) set on this media section, if any.
+    ///
+    /// This ignores the session-level direction, see [`MediaDescription::effective_direction`].
+    pub fn direction(&self) -> Option<Direction> {
+        self.attributes
+            .iter()
+            .find_map(|attribute| match attribute {
+                Attribute::Direction(direction) => Some(*direction),
+                _ => None,
+            })
+    }
+
+    /// Returns the direction the media actually flows in: the media-level direction if set,
+    /// otherwise the one of `session`, and `sendrecv` if neither has one.
+    ///
+    /// see https://tools.ietf.org/html/rfc8866#section-6.7
+    pub fn effective_direction(&self, session: &SessionDescription<'_>) -> Direction {
+        self.direction()
+            .or_else(|| session.direction())
+            .unwrap_or(Direction::SendRecv)
+    }
+
+    /// Returns the packet time (`a=ptime:`) of the media in milliseconds, if present.
+    pub fn ptime(&self) -> Option<u32> {
+        self.attributes
+            .iter()
+            .find_map(|attribute| match attribute {
+                Attribute::Ptime(ptime) => Some(*ptime),
+                _ => None,
+            })
+    }
+
+    /// Returns the maximum video frame rate (`a=framerate:`) in frames per second, if present.
+    pub fn framerate(&self) -> Option<f64> {
+        self.attributes
+            .iter()
+            .find_map(|attribute| match attribute {
+                Attribute::Framerate(framerate) => Some(framerate.value()),
+                _ => None,
+            })
+    }
+
+    /// Returns the reference clocks (`a=ts-refclk:`) of the media, in the order they appeared.
+    ///
+    /// Only media-level clocks are returned; session-level ones are in
+    /// [`SessionDescription::attributes`].
+    pub fn ts_refclks(&self) -> impl Iterator<Item = &TsRefClk<'a>> {
+        self.attributes
+            .iter()
+            .filter_map(|attribute| match attribute {
+                Attribute::TsRefClk(refclk) => Some(refclk),
+                _ => None,
+            })
+    }
+
+    /// Returns the media clock (`a=mediaclk:`) of the media, if present.
+    pub fn mediaclk(&self) -> Option<&MediaClk<'a>> {
+        self.attributes
+            .iter()
+            .find_map(|attribute| match attribute {
+                Attribute::MediaClk(mediaclk) => Some(mediaclk),
+                _ => None,
+            })
+    }
+
+    /// Returns the maximum packet time (`a=maxptime:`) of the media in milliseconds, if present.
+    pub fn maxptime(&self) -> Option<u32> {
+        self.attributes
+            .iter()
+            .find_map(|attribute| match attribute {
+                Attribute::MaxPtime(maxptime) => Some(*maxptime),
+                _ => None,
+            })
+    }
+
+    /// Returns the SCTP port of a data channel media (`a=sctp-port:`), if present.
+    pub fn sctp_port(&self) -> Option<u32> {
+        self.attributes
+            .iter()
+            .find_map(|attribute| match attribute {
+                Attribute::SctpPort(port) => Some(*port),
+                _ => None,
+            })
+    }
+
+    /// Returns the largest message size, in bytes, a data channel media accepts
+    /// (`a=max-message-size:`), if present. `0` means there is no limit.
+    pub fn max_message_size(&self) -> Option<u32> {
+        self.attributes
+            .iter()
+            .find_map(|attribute| match attribute {
+                Attribute::MaxMessageSize(size) => Some(*size),
+                _ => None,
+            })
+    }
+
+    /// Returns `true` if the media multiplexes RTP and RTCP on a single port (`a=rtcp-mux`).
+    pub fn rtcp_mux(&self) -> bool {
+        self.has_property("rtcp-mux")
+    }
+
+    /// Returns `true` if the media only supports multiplexed RTP and RTCP (`a=rtcp-mux-only`).
+    pub fn rtcp_mux_only(&self) -> bool {
+        self.has_property("rtcp-mux-only")
+    }
+
+    /// Returns `true` if the media supports reduced-size RTCP (`a=rtcp-rsize`, RFC 5506).
+    pub fn reduced_size_rtcp(&self) -> bool {
+        self.has_property("rtcp-rsize")
+    }
+
+    /// Returns `true` if no more candidates will be trickled for the media
+    /// (`a=end-of-candidates`).
+    pub fn end_of_candidates(&self) -> bool {
+        self.has_property("end-of-candidates")
+    }
+
+    fn has_property(&self, name: &str) -> bool {
+        self.attributes.iter().any(
+            |attribute| matches!(attribute, Attribute::Property(property) if *property == name),
+        )
+    }
+
+    /// Returns the RTSP control URL (`a=control:`) of the media, e.g. `trackID=1`, if present.
+    ///
+    /// The value is returned as-is; a relative URL is relative to the session-level control
+    /// URL or the base URL of the RTSP presentation.
+    ///
+    /// see https://tools.ietf.org/html/rfc7826#appendix-D.1.1
+    pub fn control(&self) -> Option<&'a str> {
+        self.attributes
+            .iter()
+            .find_map(|attribute| match attribute {
+                Attribute::Control(control) => Some(*control),
+                _ => None,
+            })
+    }
+
+    /// Returns the identification tag (`a=mid:`) of the media, if present.
+    pub fn mid(&self) -> Option<&'a str> {
+        self.attributes
+            .iter()
+            .find_map(|attribute| match attribute {
+                Attribute::MediaId(mid) => Some(*mid),
+                _ => None,
+            })
+    }
+
+    pub(crate) fn set_information(&mut self, information: SessionInformation<'a>) {
+        self.information = Some(information);
+    }
+
+    pub(crate) fn push_connection(&mut self, connection: ConnectionInformation<'a>) {
+        self.connections.push(connection);
+    }
+
+    pub(crate) fn retain_formats(&mut self, f: impl FnMut(&&'a str) -> bool) {
+        self.verbatim_lines.clear();
+        self.formats.retain(f);
+    }
+
+    /// Replaces the media-level direction attribute, or adds one if there is none.
+    pub(crate) fn set_direction(&mut self, direction: Direction) {
+        let index = self
+            .attributes
+            .iter()
+            .position(|attribute| matches!(attribute, Attribute::Direction(_)));
+        match index {
+            Some(index) => {
+                self.attributes
+                    .replace(index, Attribute::Direction(direction));
+            }
+            None => self.attributes.push(Attribute::Direction(direction)),
+        }
+    }
+
+    pub(crate) fn set_encryption_key(&mut self, encryption_key: EncryptionKey<'a>) {
+        self.encryption_key = Some(encryption_key);
+    }
+
+    pub(crate) fn push_bandwidth(&mut self, bandwidth: Bandwidth<'a>) {
+        self.bandwidths.push(bandwidth);
+    }
+
+    /// Returns the lines of the media with a `<type>` this crate does not know about, as
+    /// `(<type>, <value>)` pairs in the order they appeared.
+    pub fn unknown_lines(&self) -> &[(char, &'a str)] {
+        self.attributes.unknown_lines()
+    }
+
+    pub(crate) fn push_attribute(&mut self, attribute: Attribute<'a>, line: Option<&'a str>) {
+        self.attributes.push_line(attribute, line);
+    }
+
+    pub(crate) fn push_unknown_line(
+        &mut self,
+        line_type: char,
+        value: &'a str,
+        line: Option<&'a str>,
+    ) {
+        self.attributes.push_unknown_line(line_type, value, line);
+    }
+
+    /// Keeps the text of a line other than an attribute or unknown line, see
+    /// [`VerbatimLines`].
+    pub(crate) fn push_verbatim_line(&mut self, line: &'a str) {
+        self.verbatim_lines.push(line);
+    }
+}
+
+impl Rebind for MediaDescription<'_> {
+    type Output<'b> = MediaDescription<'b>;
+
+    fn rebind<'b>(&self, strings: &mut dyn FnMut(&str) -> &'b str) -> MediaDescription<'b> {
+        MediaDescription {
+            media: strings(self.media),
+            port: self.port,
+            port_count: self.port_count,
+            proto: self.proto.rebind(strings),
+            formats: self.formats.rebind(strings),
+            information: self.information.rebind(strings),
+            connections: self.connections.rebind(strings),
+            bandwidths: self.bandwidths.rebind(strings),
+            encryption_key: self.encryption_key.rebind(strings),
+            attributes: self.attributes.rebind(strings),
+            verbatim_lines: self.verbatim_lines.rebind(strings),
+        }
+    }
+}
+
+impl fmt::Display for MediaDescription<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_lines(f, &|f, attributes| write!(f, "{}", attributes), true)
+    }
+}
+
+impl MediaDescription<'_> {
+    /// Writes the lines of the media description, the `a=` lines (and the unknown lines among
+    /// them) with `write_attributes`.
+    pub(crate) fn write_lines(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        write_attributes: &impl Fn(&mut fmt::Formatter<'_>, &AttributeList<'_>) -> fmt::Result,
+        verbatim: bool,
+    ) -> fmt::Result {
+        if !(verbatim && self.verbatim_lines.write(f)?) {
+            self.write_typed_lines(f)?;
+        }
+        write_attributes(f, &self.attributes)
+    }
+
+    /// Writes the `m=` line and the media-level lines other than the attributes and unknown
+    /// lines.
+    fn write_typed_lines(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "m={} {}", self.media, self.port)?;
+        if let Some(port_count) = self.port_count {
+            write!(f, "/{}", port_count)?;
+        }
+        write!(f, " {}", self.proto)?;
+        for format in &self.formats {
+            write!(f, " {}", format)?;
+        }
+        write!(f, "\r\n")?;
+        if let Some(information) = &self.information {
+            write!(f, "{}", information)?;
+        }
+        for connection in &self.connections {
+            write!(f, "{}", connection)?;
+        }
+        for bandwidth in &self.bandwidths {
+            write!(f, "{}", bandwidth)?;
+        }
+        if let Some(encryption_key) = &self.encryption_key {
+            write!(f, "{}", encryption_key)?;
+        }
+        Ok(())
+    }
+}
+
+fn parse_token<'i, E: ParseError<&'i str>>(input: &'i str) -> IResult<&'i str, &'i str, E> {
+    take_till1(|c: char| c.is_whitespace()).parse(input)
+}
+
+/// m=<media> <port>/<number of ports> <proto> <fmt> ...
+/// m=audio 49170 RTP/AVP 0
+/// see https://tools.ietf.org/html/rfc8866#section-5.14
+pub fn parse_media<
+    'i,
+    E: ParseError<&'i str> + FromExternalError<&'i str, std::num::ParseIntError>,
+>(
+    input: &'i str,
+) -> IResult<&'i str, MediaDescription<'i>, E> {
+    let (tail, _) = tag("m=").parse(input)?;
+    let (tail, media) = terminated(parse_token, space1).parse(tail)?;
+    let (tail, port) = u16(tail)?;
+    let (tail, port_count) = terminated(opt(preceded(char('/'), u16)), space1).parse(tail)?;
+    let (tail, proto) = parse_token(tail)?;
+    let (tail, formats) =
+        terminated(many1(preceded(space1, parse_token)), opt(line_ending)).parse(tail)?;
+
+    Ok((
+        tail,
+        MediaDescription::new(media, port, port_count, proto, formats),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_media() {
+        let (tail, value) = parse_media::<()>("m=audio 49170 RTP/AVP 0\r\n").unwrap();
+        assert_eq!(tail, "");
+        assert_eq!(value.media, "audio");
+        assert_eq!(value.port, 49170);
+        assert_eq!(value.port_count, None);
+        assert_eq!(value.proto, MediaProto::RtpAvp);
+        assert_eq!(value.formats, vec!["0"]);
+    }
+
+    #[test]
+    fn test_parse_media_multiple_formats() {
+        let (tail, value) =
+            parse_media::<()>("m=video 51372 UDP/TLS/RTP/SAVPF 96 97 98\r\n").unwrap();
+        assert_eq!(tail, "");
+        assert_eq!(value.media, "video");
+        assert_eq!(value.proto, MediaProto::UdpTlsRtpSavpf);
+        assert_eq!(value.formats, vec!["96", "97", "98"]);
+    }
+
+    #[test]
+    fn test_parse_media_proto() {
+        let (_, value) = parse_media::<()>("m=video 51372 UDP/TLS/RTP/SAVPF 96\r\n").unwrap();
+        assert_eq!(value.media_proto(), MediaProto::UdpTlsRtpSavpf);
+        assert!(value.media_proto().is_secure());
+
+        let (_, value) = parse_media::<()>("m=application 9 TCP/MRCPv2 1\r\n").unwrap();
+        assert_eq!(value.media_proto(), MediaProto::Other("TCP/MRCPv2"));
+        assert_eq!(value.proto(), "TCP/MRCPv2");
+    }
+
+    #[test]
+    fn test_parse_media_port_count() {
+        let (tail, value) = parse_media::<()>("m=audio 49170/2 RTP/AVP 0\r\n").unwrap();
+        assert_eq!(tail, "");
+        assert_eq!(value.port(), 49170);
+        assert_eq!(value.port_count(), Some(2));
+        assert_eq!(value.proto(), "RTP/AVP");
+
+        let (_, value) = parse_media::<()>("m=audio 49170 RTP/AVP 0\r\n").unwrap();
+        assert_eq!(value.port_count(), None);
+
+        assert!(parse_media::<()>("m=audio 49170/ RTP/AVP 0\r\n").is_err());
+        assert!(parse_media::<()>("m=audio 49170/x RTP/AVP 0\r\n").is_err());
+    }
+
+    #[test]
+    fn test_media_display() {
+        let input = "m=video 51372/2 RTP/AVP 96 97\r\n";
+        let (_, value) = parse_media::<()>(input).unwrap();
+        assert_eq!(value.to_string(), input);
+    }
+
+    #[test]
+    fn test_parse_media_without_formats() {
+        assert!(parse_media::<()>("m=audio 49170 RTP/AVP\r\n").is_err());
+    }
+}
@@ -0,0 +1,5 @@
+mod media;
+mod proto;
+pub(crate) use media::parse_media;
+pub use media::MediaDescription;
+pub use proto::MediaProto;
@@ -0,0 +1,146 @@
+use std::fmt;
+
+use crate::session_desription::Rebind;
+
+/// The transport protocol of an `m=` line.
+///
+/// Protocols this crate does not know are kept as written in [`MediaProto::Other`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MediaProto<'a> {
+    /// `RTP/AVP`, RTP with the audio/video profile (RFC 3551).
+    RtpAvp,
+    /// `RTP/AVPF`, with RTCP-based feedback (RFC 4585).
+    RtpAvpf,
+    /// `RTP/SAVP`, secure RTP (RFC 3711).
+    RtpSavp,
+    /// `RTP/SAVPF`, secure RTP with RTCP-based feedback (RFC 5124).
+    RtpSavpf,
+    /// `UDP/TLS/RTP/SAVP`, secure RTP keyed with DTLS over UDP (RFC 5764).
+    UdpTlsRtpSavp,
+    /// `UDP/TLS/RTP/SAVPF`, as used by WebRTC (RFC 5764).
+    UdpTlsRtpSavpf,
+    /// `TCP/TLS/RTP/SAVPF`, as used by WebRTC over TCP (RFC 7850).
+    TcpTlsRtpSavpf,
+    /// `UDP/DTLS/SCTP`, SCTP over DTLS over UDP, as used by WebRTC data channels (RFC 8841).
+    UdpDtlsSctp,
+    /// `TCP/DTLS/SCTP`, SCTP over DTLS over TCP (RFC 8841).
+    TcpDtlsSctp,
+    /// `udp`, an unspecified protocol over UDP (RFC 8866 §5.14).
+    Udp,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    Other(&'a str),
+}
+
+impl<'a> MediaProto<'a> {
+    pub fn as_str(&self) -> &'a str {
+        match self {
+            MediaProto::RtpAvp => "RTP/AVP",
+            MediaProto::RtpAvpf => "RTP/AVPF",
+            MediaProto::RtpSavp => "RTP/SAVP",
+            MediaProto::RtpSavpf => "RTP/SAVPF",
+            MediaProto::UdpTlsRtpSavp => "UDP/TLS/RTP/SAVP",
+            MediaProto::UdpTlsRtpSavpf => "UDP/TLS/RTP/SAVPF",
+            MediaProto::TcpTlsRtpSavpf => "TCP/TLS/RTP/SAVPF",
+            MediaProto::UdpDtlsSctp => "UDP/DTLS/SCTP",
+            MediaProto::TcpDtlsSctp => "TCP/DTLS/SCTP",
+            MediaProto::Udp => "udp",
+            MediaProto::Other(proto) => proto,
+        }
+    }
+
+    /// Returns `true` for the RTP profiles.
+    pub fn is_rtp(&self) -> bool {
+        !matches!(
+            self,
+            MediaProto::UdpDtlsSctp
+                | MediaProto::TcpDtlsSctp
+                | MediaProto::Udp
+                | MediaProto::Other(_)
+        )
+    }
+
+    /// Returns `true` for SCTP, i.e. data channels.
+    pub fn is_sctp(&self) -> bool {
+        matches!(self, MediaProto::UdpDtlsSctp | MediaProto::TcpDtlsSctp)
+    }
+
+    /// Returns `true` if the media is encrypted: secure RTP or SCTP over DTLS.
+    pub fn is_secure(&self) -> bool {
+        !matches!(
+            self,
+            MediaProto::RtpAvp | MediaProto::RtpAvpf | MediaProto::Udp | MediaProto::Other(_)
+        )
+    }
+}
+
+/// Maps the `<proto>` token as-is; protocol names are case-sensitive.
+impl<'a> From<&'a str> for MediaProto<'a> {
+    fn from(proto: &'a str) -> Self {
+        match proto {
+            "RTP/AVP" => MediaProto::RtpAvp,
+            "RTP/AVPF" => MediaProto::RtpAvpf,
+            "RTP/SAVP" => MediaProto::RtpSavp,
+            "RTP/SAVPF" => MediaProto::RtpSavpf,
+            "UDP/TLS/RTP/SAVP" => MediaProto::UdpTlsRtpSavp,
+            "UDP/TLS/RTP/SAVPF" => MediaProto::UdpTlsRtpSavpf,
+            "TCP/TLS/RTP/SAVPF" => MediaProto::TcpTlsRtpSavpf,
+            "UDP/DTLS/SCTP" => MediaProto::UdpDtlsSctp,
+            "TCP/DTLS/SCTP" => MediaProto::TcpDtlsSctp,
+            "udp" => MediaProto::Udp,
+            other => MediaProto::Other(other),
+        }
+    }
+}
+
+impl Rebind for MediaProto<'_> {
+    type Output<'b> = MediaProto<'b>;
+
+    fn rebind<'b>(&self, strings: &mut dyn FnMut(&str) -> &'b str) -> MediaProto<'b> {
+        // the known protocols map back to their variant
+        MediaProto::from(strings(self.as_str()))
+    }
+}
+
+impl fmt::Display for MediaProto<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_media_proto() {
+        for (input, expected, rtp, sctp, secure) in [
+            ("RTP/AVP", MediaProto::RtpAvp, true, false, false),
+            ("RTP/SAVPF", MediaProto::RtpSavpf, true, false, true),
+            (
+                "UDP/TLS/RTP/SAVPF",
+                MediaProto::UdpTlsRtpSavpf,
+                true,
+                false,
+                true,
+            ),
+            ("UDP/DTLS/SCTP", MediaProto::UdpDtlsSctp, false, true, true),
+            ("udp", MediaProto::Udp, false, false, false),
+            (
+                "TCP/MRCPv2",
+                MediaProto::Other("TCP/MRCPv2"),
+                false,
+                false,
+                false,
+            ),
+        ] {
+            let proto = MediaProto::from(input);
+            assert_eq!(proto, expected);
+            assert_eq!(proto.is_rtp(), rtp, "{}", input);
+            assert_eq!(proto.is_sctp(), sctp, "{}", input);
+            assert_eq!(proto.is_secure(), secure, "{}", input);
+            assert_eq!(proto.to_string(), input);
+        }
+        assert_eq!(MediaProto::from("rtp/avp"), MediaProto::Other("rtp/avp"));
+    }
+}
@@ -0,0 +1,55 @@
+/// Options controlling how an SDP session description is parsed.
+///
+/// The default is [`ParseOptions::strict`], [`ParseOptions::lenient`] accepts what real-world
+/// endpoints tend to send.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseOptions {
+    /// Keep the obsolete `k=` encryption key lines, at session and media level, instead of
+    /// discarding them.
+    pub keep_encryption_key: bool,
+    /// Accept lines in any order instead of the one RFC 8866 requires.
+    pub allow_out_of_order: bool,
+    /// Accept a session without `t=` line, treating it as permanent (`t=0 0`).
+    pub allow_missing_time: bool,
+    /// Keep lines of an unknown `<type>` (see `unknown_lines()`) instead of discarding them.
+    pub preserve_unknown_lines: bool,
+    /// Keep the text of every line, line ending included, and serialize the untouched ones
+    /// back verbatim, see [`AttributeList`](crate::AttributeList). The lines of a media
+    /// section other than its attributes are serialized from their typed values again once
+    /// it is modified, e.g. rejected.
+    pub preserve_attribute_lines: bool,
+    /// Keep an `a=` line whose value does not parse as its typed attribute, e.g.
+    /// `a=rtpmap:0 PCMU`, as an untyped [`Attribute::Value`](crate::Attribute::Value) instead
+    /// of failing.
+    pub allow_malformed_attributes: bool,
+}
+
+impl ParseOptions {
+    /// Options enforcing the line order and the mandatory lines of RFC 8866.
+    pub fn strict() -> Self {
+        Self {
+            keep_encryption_key: false,
+            allow_out_of_order: false,
+            allow_missing_time: false,
+            preserve_unknown_lines: true,
+            preserve_attribute_lines: false,
+            allow_malformed_attributes: false,
+        }
+    }
+
+    /// Options accepting out of order lines, a missing `t=` line and malformed attributes.
+    pub fn lenient() -> Self {
+        Self {
+            allow_out_of_order: true,
+            allow_missing_time: true,
+            allow_malformed_attributes: true,
+            ..Self::strict()
+        }
+    }
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        Self::strict()
+    }
+}
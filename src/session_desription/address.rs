@@ -0,0 +1,129 @@
+use std::{fmt, net::IpAddr};
+
+use nom::{bytes::complete::take_till1, combinator::map_opt, error::ParseError, IResult, Parser};
+
+use super::Rebind;
+
+/// The address of an `o=` or `c=` line: either a numeric IP address or a fully qualified
+/// domain name.
+///
+/// see https://tools.ietf.org/html/rfc8866#section-5.7
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ConnectionAddress<'a> {
+    Ip(IpAddr),
+    Fqdn(&'a str),
+}
+
+impl From<IpAddr> for ConnectionAddress<'_> {
+    fn from(address: IpAddr) -> Self {
+        ConnectionAddress::Ip(address)
+    }
+}
+
+/// Takes `name` as a domain name as-is; use [`str::parse`] on [`IpAddr`] for IP literals.
+impl<'a> From<&'a str> for ConnectionAddress<'a> {
+    fn from(name: &'a str) -> Self {
+        ConnectionAddress::Fqdn(name)
+    }
+}
+
+impl Rebind for ConnectionAddress<'_> {
+    type Output<'b> = ConnectionAddress<'b>;
+
+    fn rebind<'b>(&self, strings: &mut dyn FnMut(&str) -> &'b str) -> ConnectionAddress<'b> {
+        match self {
+            ConnectionAddress::Ip(address) => ConnectionAddress::Ip(*address),
+            ConnectionAddress::Fqdn(name) => ConnectionAddress::Fqdn(strings(name)),
+        }
+    }
+}
+
+impl fmt::Display for ConnectionAddress<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConnectionAddress::Ip(address) => write!(f, "{}", address),
+            ConnectionAddress::Fqdn(name) => write!(f, "{}", name),
+        }
+    }
+}
+
+/// Returns `true` if `s` looks like a domain name, i.e. it only holds letters, digits, `-` and
+/// `.` and is not purely numeric (so a mistyped IPv4 address is not taken for a host name).
+fn is_fqdn(s: &str) -> bool {
+    s.chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '.')
+        && s.chars().any(|c| c.is_ascii_alphabetic())
+}
+
+/// Parses the address token up to the next whitespace or `/`.
+pub(crate) fn parse_connection_address<'i, E: ParseError<&'i str>>(
+    input: &'i str,
+) -> IResult<&'i str, ConnectionAddress<'i>, E> {
+    map_opt(
+        take_till1(|c: char| c == '/' || c.is_whitespace()),
+        |s: &str| match s.parse::<IpAddr>() {
+            Ok(address) => Some(ConnectionAddress::Ip(address)),
+            Err(_) if is_fqdn(s) => Some(ConnectionAddress::Fqdn(s)),
+            Err(_) => None,
+        },
+    )
+    .parse(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{Ipv4Addr, Ipv6Addr};
+
+    use super::*;
+
+    #[test]
+    fn test_parse_ipv4_address() {
+        let (tail, value) = parse_connection_address::<()>("192.168.10.1\r\n").unwrap();
+        assert_eq!(tail, "\r\n");
+        assert_eq!(
+            value,
+            ConnectionAddress::Ip(IpAddr::V4(Ipv4Addr::new(192, 168, 10, 1)))
+        );
+    }
+
+    #[test]
+    fn test_parse_ipv6_address() {
+        let (_, value) = parse_connection_address::<()>("::1").unwrap();
+        assert_eq!(
+            value,
+            ConnectionAddress::Ip(IpAddr::V6(Ipv6Addr::new(0, 0, 0, 0, 0, 0, 0, 1)))
+        );
+    }
+
+    #[test]
+    fn test_connection_address_from() {
+        let address: ConnectionAddress = IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)).into();
+        assert_eq!(
+            address,
+            ConnectionAddress::Ip(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)))
+        );
+        assert_eq!(address.to_string(), "10.0.0.1");
+
+        let address: ConnectionAddress = IpAddr::V6(Ipv6Addr::LOCALHOST).into();
+        assert_eq!(address.to_string(), "::1");
+
+        let address = ConnectionAddress::from("host.example.com");
+        assert_eq!(address, ConnectionAddress::Fqdn("host.example.com"));
+        assert_eq!(address.to_string(), "host.example.com");
+    }
+
+    #[test]
+    fn test_parse_fqdn() {
+        let (tail, value) = parse_connection_address::<()>("host.example.com/127").unwrap();
+        assert_eq!(tail, "/127");
+        assert_eq!(value, ConnectionAddress::Fqdn("host.example.com"));
+        assert_eq!(value.to_string(), "host.example.com");
+    }
+
+    #[test]
+    fn test_parse_invalid_address() {
+        assert!(parse_connection_address::<()>("192.168.10.256").is_err());
+        assert!(parse_connection_address::<()>("host_name!").is_err());
+    }
+}
@@ -0,0 +1,336 @@
+//! Generators of random but valid session descriptions, used to check that parsing is the
+//! inverse of [`fmt::Display`](std::fmt::Display).
+
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use super::*;
+use crate::attribute::{
+    ExtMap, Fmtp, Framerate, MediaClk, Rid, RidDirection, RtpMap, Setup, Simulcast, Ssrc,
+    SsrcGroup, TsRefClk,
+};
+use crate::time_description::{RepeatTimes, TimeZone};
+
+/// A xorshift generator, good enough to drive the generators and reproducible from its seed.
+struct Rng(u64);
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        Rng(seed.wrapping_mul(0x9e37_79b9_7f4a_7c15) | 1)
+    }
+
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, n: u64) -> u64 {
+        self.next() % n
+    }
+
+    fn chance(&mut self) -> bool {
+        self.below(2) == 0
+    }
+
+    fn pick<T: Copy>(&mut self, items: &[T]) -> T {
+        items[self.below(items.len() as u64) as usize]
+    }
+
+    fn maybe<T>(&mut self, f: impl FnOnce(&mut Self) -> T) -> Option<T> {
+        self.chance().then(|| f(self))
+    }
+
+    fn repeat<T>(&mut self, max: u64, mut f: impl FnMut(&mut Self) -> T) -> Vec<T> {
+        (0..self.below(max + 1)).map(|_| f(self)).collect()
+    }
+}
+
+fn ip_address(rng: &mut Rng) -> IpAddr {
+    if rng.chance() {
+        IpAddr::V4(Ipv4Addr::from(rng.next() as u32))
+    } else {
+        IpAddr::V6(Ipv6Addr::from(
+            (u128::from(rng.next()) << 64) | u128::from(rng.next()),
+        ))
+    }
+}
+
+fn origin(rng: &mut Rng) -> Origin<'static> {
+    let address = if rng.below(4) == 0 {
+        ConnectionAddress::Fqdn(rng.pick(&["host.example.com", "localhost"]))
+    } else {
+        ConnectionAddress::Ip(ip_address(rng))
+    };
+    let addrtype = match &address {
+        ConnectionAddress::Ip(address) => AddrType::from_addr(address),
+        ConnectionAddress::Fqdn(_) if rng.chance() => AddrType::IP4,
+        ConnectionAddress::Fqdn(_) => AddrType::IP6,
+    };
+    Origin::new(
+        rng.pick(&["-", "jdoe", "j.doe"]),
+        rng.pick(&["2890844526", "4858251974351650128", "1"]),
+        rng.next(),
+        NetType::IN,
+        addrtype,
+        address,
+    )
+}
+
+fn connection(rng: &mut Rng) -> ConnectionInformation<'static> {
+    match rng.below(3) {
+        0 => {
+            let address = Ipv4Addr::from(rng.next() as u32);
+            let ttl = rng.maybe(|rng| rng.next() as u8);
+            let num_addresses = ttl.and_then(|_| rng.maybe(|rng| rng.below(16) as u32 + 1));
+            ConnectionInformation::new(
+                NetType::IN,
+                AddrType::IP4,
+                ConnectionAddress::Ip(IpAddr::V4(address)),
+                ttl,
+                num_addresses,
+            )
+        }
+        1 => {
+            let address = Ipv6Addr::from((u128::from(rng.next()) << 64) | u128::from(rng.next()));
+            ConnectionInformation::new(
+                NetType::IN,
+                AddrType::IP6,
+                ConnectionAddress::Ip(IpAddr::V6(address)),
+                rng.maybe(|rng| rng.next() as u8),
+                rng.maybe(|rng| rng.below(16) as u32 + 1),
+            )
+        }
+        _ => ConnectionInformation::new(
+            NetType::IN,
+            if rng.below(2) == 0 {
+                AddrType::IP4
+            } else {
+                AddrType::IP6
+            },
+            ConnectionAddress::Fqdn(rng.pick(&["host.example.com", "localhost"])),
+            None,
+            None,
+        ),
+    }
+}
+
+fn encryption_key(rng: &mut Rng) -> EncryptionKey<'static> {
+    match rng.below(4) {
+        0 => EncryptionKey::Clear("secret"),
+        1 => EncryptionKey::Base64("c2VjcmV0"),
+        2 => EncryptionKey::Uri("https://example.com/key"),
+        _ => EncryptionKey::Prompt,
+    }
+}
+
+fn time_zone(rng: &mut Rng) -> TimeZone {
+    TimeZone::new(rng.below(1 << 32), rng.below(1 << 20) as i64 - (1 << 19))
+}
+
+/// Returns a line of a `<type>` the parser does not know about.
+fn unknown_line(rng: &mut Rng) -> (char, &'static str) {
+    (
+        rng.pick(&['x', 'y', 'w']),
+        rng.pick(&["vendor value", "1 2 3"]),
+    )
+}
+
+fn bandwidth(rng: &mut Rng) -> Bandwidth<'static> {
+    Bandwidth::new(rng.pick(&["CT", "AS", "TIAS"]), rng.next())
+}
+
+fn time_description(rng: &mut Rng) -> TimeDescription {
+    let mut time_description = TimeDescription::new(rng.below(1 << 32), rng.below(1 << 32));
+    for _ in 0..rng.below(3) {
+        let offsets = (0..=rng.below(3)).map(|_| rng.below(1 << 20)).collect();
+        time_description.push_repeat_times(RepeatTimes::new(
+            rng.below(1 << 20),
+            rng.below(1 << 20),
+            offsets,
+        ));
+    }
+    time_description
+}
+
+fn direction(rng: &mut Rng) -> Direction {
+    rng.pick(&[
+        Direction::SendRecv,
+        Direction::SendOnly,
+        Direction::RecvOnly,
+        Direction::Inactive,
+    ])
+}
+
+fn session_attribute(rng: &mut Rng) -> Attribute<'static> {
+    match rng.below(5) {
+        0 => Attribute::Property(rng.pick(&["ice-lite", "extmap-allow-mixed"])),
+        1 => Attribute::Value("tool", rng.pick(&["libsdp 0.1", "sdp-parser"])),
+        2 => Attribute::Direction(direction(rng)),
+        3 => Attribute::IceOptions(
+            (0..=rng.below(2))
+                .map(|_| rng.pick(&["trickle", "renomination"]))
+                .collect(),
+        ),
+        _ => Attribute::Fingerprint(Fingerprint::new("sha-256", "AB:CD:EF:01:23:45")),
+    }
+}
+
+fn media_attribute(rng: &mut Rng) -> Attribute<'static> {
+    match rng.below(16) {
+        0 => Attribute::Property(rng.pick(&["rtcp-mux", "rtcp-mux-only", "rtcp-rsize"])),
+        1 => Attribute::Direction(direction(rng)),
+        2 => Attribute::RtpMap(RtpMap::new(
+            rng.below(128) as u8,
+            rng.pick(&["PCMU", "opus", "VP8"]),
+            rng.pick(&[8000, 48000, 90000]),
+            rng.maybe(|rng| rng.below(8) as u8 + 1),
+        )),
+        3 => Attribute::Fmtp(Fmtp::new(
+            rng.below(128) as u8,
+            rng.pick(&["minptime=10;useinbandfec=1", "apt=96"]),
+        )),
+        4 => Attribute::Setup(match rng.below(3) {
+            0 => Setup::ActPass,
+            1 => Setup::Active,
+            _ => Setup::Passive,
+        }),
+        5 => Attribute::ExtMap(ExtMap::new(
+            rng.below(14) as u8 + 1,
+            rng.maybe(direction),
+            "urn:ietf:params:rtp-hdrext:ssrc-audio-level",
+            None,
+        )),
+        6 => Attribute::Ssrc(Ssrc::new(
+            rng.next() as u32,
+            "cname",
+            rng.maybe(|rng| rng.pick(&["user@example.com", "4TOk42mSjXCkVIa6"])),
+        )),
+        7 => Attribute::SsrcGroup(SsrcGroup::new(
+            "FID",
+            rng.repeat(3, |rng| rng.next() as u32),
+        )),
+        8 => Attribute::Ptime(rng.below(120) as u32 + 1),
+        9 => Attribute::MaxPtime(rng.below(120) as u32 + 1),
+        10 => Attribute::Rid(Rid::new(
+            rng.pick(&["hi", "mid", "lo"]),
+            rng.pick(&[RidDirection::Send, RidDirection::Recv]),
+            rng.maybe(|rng| rng.pick(&["pt=96", "max-width=1280;max-height=720"])),
+        )),
+        11 => Attribute::Simulcast(Simulcast::new(
+            vec![vec![rng.pick(&["hi", "~hi"])], vec!["lo"]],
+            rng.repeat(2, |rng| vec![rng.pick(&["1", "~1"]), "2"]),
+        )),
+        12 => Attribute::Framerate(Framerate::new(rng.pick(&["30", "29.97", "12.5"]))),
+        13 => Attribute::TsRefClk(TsRefClk::new(
+            rng.pick(&["ptp", "localmac", "local"]),
+            rng.maybe(|rng| rng.pick(&["IEEE1588-2008:39-A7-94-FF-FE-07-CB-D0:37", "traceable"])),
+        )),
+        14 => Attribute::MediaClk(MediaClk::new(
+            rng.pick(&["direct", "sender"]),
+            rng.maybe(|rng| rng.pick(&["0", "963214424"])),
+            rng.maybe(|rng| rng.pick(&["rate=90000/1", "rate=1000/1001"])),
+        )),
+        _ => Attribute::Value("label", rng.pick(&["1", "main"])),
+    }
+}
+
+fn media(rng: &mut Rng, mid: Option<&'static str>) -> MediaDescription<'static> {
+    let mut media = MediaDescription::new(
+        rng.pick(&["audio", "video", "application"]),
+        rng.next() as u16,
+        rng.maybe(|rng| rng.below(4) as u16 + 1),
+        rng.pick(&[
+            "RTP/AVP",
+            "UDP/TLS/RTP/SAVPF",
+            "UDP/DTLS/SCTP",
+            "TCP/MRCPv2",
+        ]),
+        (0..=rng.below(3))
+            .map(|_| rng.pick(&["0", "8", "96", "111"]))
+            .collect(),
+    );
+    if let Some(information) = rng.maybe(|rng| rng.pick(&["Audio", "Main video feed"])) {
+        media.set_information(SessionInformation::new(information));
+    }
+    for connection in rng.repeat(2, connection) {
+        media.push_connection(connection);
+    }
+    for bandwidth in rng.repeat(2, bandwidth) {
+        media.push_bandwidth(bandwidth);
+    }
+    if let Some(encryption_key) = rng.maybe(encryption_key) {
+        media.set_encryption_key(encryption_key);
+    }
+    if let Some(mid) = mid {
+        media.push_attribute(Attribute::MediaId(mid), None);
+    }
+    for attribute in rng.repeat(6, media_attribute) {
+        if let Some((line_type, value)) = rng.maybe(unknown_line) {
+            media.push_unknown_line(line_type, value, None);
+        }
+        media.push_attribute(attribute, None);
+    }
+    for (line_type, value) in rng.repeat(1, unknown_line) {
+        media.push_unknown_line(line_type, value, None);
+    }
+    media
+}
+
+/// Builds a random session description that only uses what the parser accepts in strict mode.
+fn session_description(rng: &mut Rng) -> SessionDescription<'static> {
+    let mids = ["0", "1", "2"][..rng.below(4) as usize].to_vec();
+    let mut attributes = AttributeList::new();
+    for attribute in rng.repeat(3, session_attribute) {
+        if let Some((line_type, value)) = rng.maybe(unknown_line) {
+            attributes.push_unknown_line(line_type, value, None);
+        }
+        attributes.push(attribute);
+    }
+    if !mids.is_empty() {
+        attributes.push(Attribute::Group(Group::new("BUNDLE", mids.clone())));
+    }
+    for (line_type, value) in rng.repeat(1, unknown_line) {
+        attributes.push_unknown_line(line_type, value, None);
+    }
+    let mut media_descriptions: Vec<_> = mids.iter().map(|mid| media(rng, Some(mid))).collect();
+    media_descriptions.extend(rng.repeat(2, |rng| media(rng, None)));
+    SessionDescription {
+        version: Version::new(0),
+        origin: origin(rng),
+        session_name: SessionName::new(rng.pick(&["-", "SDP Seminar", "Séance"])),
+        information: rng.maybe(|rng| SessionInformation::new(rng.pick(&["A Seminar", "-"]))),
+        uri: rng.maybe(|_| Uri::new("http://www.example.com/seminars/sdp.pdf")),
+        emails: rng.repeat(2, |rng| {
+            Email::new(rng.pick(&["j.doe@example.com", "j.doe@example.com (Jane Doe)"]))
+        }),
+        phones: rng.repeat(2, |rng| {
+            Phone::new(rng.pick(&["+1 617 555-6011", "+44-171-3"]))
+        }),
+        connection: rng.maybe(connection),
+        bandwidths: rng.repeat(2, bandwidth),
+        time_descriptions: (0..=rng.below(2)).map(|_| time_description(rng)).collect(),
+        time_zones: rng.repeat(2, time_zone),
+        encryption_key: rng.maybe(encryption_key),
+        attributes,
+        media: media_descriptions,
+        duplicate_lines: Vec::new(),
+        verbatim_lines: VerbatimLines::default(),
+    }
+}
+
+#[test]
+fn test_round_trip_generated_session_descriptions() {
+    for seed in 0..512 {
+        let session = session_description(&mut Rng::new(seed));
+        let text = session.to_string();
+        let options = ParseOptions {
+            keep_encryption_key: true,
+            ..ParseOptions::default()
+        };
+        let parsed = SessionDescription::from_str_with_options(&text, &options)
+            .unwrap_or_else(|e| panic!("seed {}: {} while parsing\n{}", seed, e, text));
+        assert_eq!(parsed, session, "seed {}:\n{}", seed, text);
+        assert_eq!(session.into_owned().session_description(), &parsed);
+    }
+}
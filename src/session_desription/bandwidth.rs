@@ -0,0 +1,123 @@
+use std::fmt;
+
+use nom::{
+    bytes::complete::{tag, take_till1},
+    character::complete::{char, line_ending, u64},
+    combinator::opt,
+    error::{FromExternalError, ParseError},
+    sequence::{separated_pair, terminated},
+    IResult, Parser,
+};
+
+use super::Rebind;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Bandwidth<'a> {
+    bwtype: &'a str,
+    bandwidth: u64,
+}
+
+impl<'a> Bandwidth<'a> {
+    pub fn new(bwtype: &'a str, bandwidth: u64) -> Self {
+        Self { bwtype, bandwidth }
+    }
+
+    /// Returns the bandwidth type as it appeared, e.g. `AS`, `CT`, `TIAS`, `RR`, `RS` or an
+    /// experimental `X-` type.
+    pub fn bwtype(&self) -> &'a str {
+        self.bwtype
+    }
+
+    /// Returns the bandwidth, in kilobits per second for `AS` and `CT` and in bits per second
+    /// for `TIAS`, `RR` and `RS`.
+    pub fn bandwidth(&self) -> u64 {
+        self.bandwidth
+    }
+}
+
+impl Rebind for Bandwidth<'_> {
+    type Output<'b> = Bandwidth<'b>;
+
+    fn rebind<'b>(&self, strings: &mut dyn FnMut(&str) -> &'b str) -> Bandwidth<'b> {
+        Bandwidth {
+            bwtype: strings(self.bwtype),
+            bandwidth: self.bandwidth,
+        }
+    }
+}
+
+impl fmt::Display for Bandwidth<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "b={}:{}\r\n", self.bwtype, self.bandwidth)
+    }
+}
+
+/// b=<bwtype>:<bandwidth>
+/// b=AS:128
+/// see https://tools.ietf.org/html/rfc8866#section-5.8
+pub fn parse_bandwidth<
+    'i,
+    E: ParseError<&'i str> + FromExternalError<&'i str, std::num::ParseIntError>,
+>(
+    input: &'i str,
+) -> IResult<&'i str, Bandwidth<'i>, E> {
+    let (tail, _) = tag("b=").parse(input)?;
+    let (tail, (bwtype, bandwidth)) = terminated(
+        separated_pair(
+            take_till1(|c: char| c == ':' || c.is_whitespace()),
+            char(':'),
+            u64,
+        ),
+        opt(line_ending),
+    )
+    .parse(tail)?;
+    Ok((tail, Bandwidth::new(bwtype, bandwidth)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_bandwidth() {
+        let (tail, value) = parse_bandwidth::<()>("b=AS:128\r\n").unwrap();
+        assert_eq!(tail, "");
+        assert_eq!(value.bwtype, "AS");
+        assert_eq!(value.bandwidth, 128);
+    }
+
+    #[test]
+    fn test_parse_bandwidth_conference_total() {
+        let (_, value) = parse_bandwidth::<()>("b=CT:512\r\n").unwrap();
+        assert_eq!(value, Bandwidth::new("CT", 512));
+    }
+
+    #[test]
+    fn test_parse_bandwidth_transport_independent() {
+        let (tail, value) = parse_bandwidth::<()>("b=TIAS:64000\r\n").unwrap();
+        assert_eq!(tail, "");
+        assert_eq!(value, Bandwidth::new("TIAS", 64000));
+        assert_eq!(value.to_string(), "b=TIAS:64000\r\n");
+    }
+
+    #[test]
+    fn test_parse_bandwidth_rtcp() {
+        let (_, value) = parse_bandwidth::<()>("b=RS:800\r\n").unwrap();
+        assert_eq!(value, Bandwidth::new("RS", 800));
+        let (_, value) = parse_bandwidth::<()>("b=RR:2000\r\n").unwrap();
+        assert_eq!(value, Bandwidth::new("RR", 2000));
+    }
+
+    #[test]
+    fn test_parse_bandwidth_experimental() {
+        let (_, value) = parse_bandwidth::<()>("b=X-YZ:64\r\n").unwrap();
+        assert_eq!(value.bwtype, "X-YZ");
+        assert_eq!(value.to_string(), "b=X-YZ:64\r\n");
+    }
+
+    #[test]
+    fn test_parse_bandwidth_not_numeric() {
+        assert!(parse_bandwidth::<()>("b=AS:lots\r\n").is_err());
+    }
+}
@@ -0,0 +1,238 @@
+use std::fmt;
+
+use nom::{
+    bytes::complete::tag,
+    character::complete::{char, line_ending, u32, u8},
+    combinator::{not, opt},
+    error::{FromExternalError, ParseError},
+    sequence::{preceded, terminated},
+    IResult, Parser,
+};
+
+use super::address::{parse_connection_address, ConnectionAddress};
+use super::origin::{
+    parse_addrtype, parse_nettype, AddrType, NetType, ParseAddrTypeError, ParseNetTypeError,
+};
+use super::Rebind;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ConnectionInformation<'a> {
+    nettype: NetType,
+    addrtype: AddrType,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    address: ConnectionAddress<'a>,
+    ttl: Option<u8>,
+    num_addresses: Option<u32>,
+}
+
+impl<'a> ConnectionInformation<'a> {
+    /// Builds the connection data. `ttl` only applies to `IP4` and is ignored for `IP6`,
+    /// which has no time-to-live.
+    pub fn new(
+        nettype: NetType,
+        addrtype: AddrType,
+        address: ConnectionAddress<'a>,
+        ttl: Option<u8>,
+        num_addresses: Option<u32>,
+    ) -> Self {
+        let ttl = match addrtype {
+            AddrType::IP4 => ttl,
+            AddrType::IP6 => None,
+        };
+        Self {
+            nettype,
+            addrtype,
+            address,
+            ttl,
+            num_addresses,
+        }
+    }
+
+    pub fn nettype(&self) -> &NetType {
+        &self.nettype
+    }
+
+    pub fn addrtype(&self) -> &AddrType {
+        &self.addrtype
+    }
+
+    pub fn address(&self) -> &ConnectionAddress<'a> {
+        &self.address
+    }
+
+    /// Returns the time-to-live of an IPv4 multicast address, i.e. the `/127` in
+    /// `224.2.36.42/127`. IPv6 addresses carry no time-to-live.
+    pub fn ttl(&self) -> Option<u8> {
+        self.ttl
+    }
+
+    /// Returns the number of contiguous multicast addresses, i.e. the `/3` in
+    /// `224.2.36.42/127/3` or `ff15::101/3`.
+    pub fn num_addresses(&self) -> Option<u32> {
+        self.num_addresses
+    }
+}
+
+impl Rebind for ConnectionInformation<'_> {
+    type Output<'b> = ConnectionInformation<'b>;
+
+    fn rebind<'b>(&self, strings: &mut dyn FnMut(&str) -> &'b str) -> ConnectionInformation<'b> {
+        ConnectionInformation {
+            nettype: self.nettype.clone(),
+            addrtype: self.addrtype.clone(),
+            address: self.address.rebind(strings),
+            ttl: self.ttl,
+            num_addresses: self.num_addresses,
+        }
+    }
+}
+
+impl fmt::Display for ConnectionInformation<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "c={} {} {}", self.nettype, self.addrtype, self.address)?;
+        if let Some(ttl) = self.ttl {
+            write!(f, "/{}", ttl)?;
+        }
+        if let Some(num_addresses) = self.num_addresses {
+            write!(f, "/{}", num_addresses)?;
+        }
+        write!(f, "\r\n")
+    }
+}
+
+/// c=<nettype> <addrtype> <connection-address>
+/// c=IN IP4 224.2.36.42/127/3
+/// c=IN IP6 ff15::101/3
+/// see https://tools.ietf.org/html/rfc8866#section-5.7
+pub fn parse_connection<
+    'i,
+    E: ParseError<&'i str>
+        + FromExternalError<&'i str, std::num::ParseIntError>
+        + FromExternalError<&'i str, ParseNetTypeError>
+        + FromExternalError<&'i str, ParseAddrTypeError>,
+>(
+    input: &'i str,
+) -> IResult<&'i str, ConnectionInformation<'i>, E> {
+    let (tail, _) = tag("c=").parse(input)?;
+    let (tail, nettype) = parse_nettype(tail)?;
+    let (tail, addrtype) = parse_addrtype(tail)?;
+    let (tail, address) = parse_connection_address(tail)?;
+    // IPv6 has no time-to-live, its only suffix is the number of addresses
+    let (tail, ttl) = match addrtype {
+        AddrType::IP4 => opt(preceded(char('/'), u8)).parse(tail)?,
+        AddrType::IP6 => (tail, None),
+    };
+    let (tail, num_addresses) = terminated(
+        opt(preceded(char('/'), u32)),
+        preceded(not(char('/')), opt(line_ending)),
+    )
+    .parse(tail)?;
+
+    Ok((
+        tail,
+        ConnectionInformation {
+            nettype,
+            addrtype,
+            address,
+            ttl,
+            num_addresses,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+    use super::*;
+
+    #[test]
+    fn test_parse_connection() {
+        let (tail, value) = parse_connection::<()>("c=IN IP4 192.168.10.1\r\n").unwrap();
+        assert_eq!(tail, "");
+        assert_eq!(value.nettype, NetType::IN);
+        assert_eq!(value.addrtype, AddrType::IP4);
+        assert_eq!(
+            value.address,
+            ConnectionAddress::Ip(IpAddr::V4(Ipv4Addr::new(192, 168, 10, 1)))
+        );
+        assert_eq!(value.ttl, None);
+        assert_eq!(value.num_addresses, None);
+    }
+
+    #[test]
+    fn test_parse_connection_multicast() {
+        let (tail, value) = parse_connection::<()>("c=IN IP4 224.2.36.42/127/3\r\n").unwrap();
+        assert_eq!(tail, "");
+        assert_eq!(
+            value.address,
+            ConnectionAddress::Ip(IpAddr::V4(Ipv4Addr::new(224, 2, 36, 42)))
+        );
+        assert_eq!(value.ttl, Some(127));
+        assert_eq!(value.num_addresses, Some(3));
+    }
+
+    #[test]
+    fn test_parse_connection_multicast_ttl_only() {
+        let (_, value) = parse_connection::<()>("c=IN IP4 224.2.36.42/127\r\n").unwrap();
+        assert_eq!(value.ttl, Some(127));
+        assert_eq!(value.num_addresses, None);
+    }
+
+    #[test]
+    fn test_parse_connection_ipv6() {
+        let (tail, value) = parse_connection::<()>("c=IN IP6 ::1\r\n").unwrap();
+        assert_eq!(tail, "");
+        assert_eq!(value.addrtype, AddrType::IP6);
+        assert_eq!(
+            value.address,
+            ConnectionAddress::Ip(IpAddr::V6(Ipv6Addr::new(0, 0, 0, 0, 0, 0, 0, 1)))
+        );
+    }
+
+    #[test]
+    fn test_parse_connection_ipv6_multicast() {
+        let (tail, value) = parse_connection::<()>("c=IN IP6 ff15::101/3\r\n").unwrap();
+        assert_eq!(tail, "");
+        assert_eq!(value.ttl(), None);
+        assert_eq!(value.num_addresses(), Some(3));
+        assert_eq!(value.to_string(), "c=IN IP6 ff15::101/3\r\n");
+    }
+
+    #[test]
+    fn test_parse_connection_ipv6_rejects_ttl() {
+        assert!(parse_connection::<()>("c=IN IP6 ff15::101/127/3\r\n").is_err());
+        assert!(parse_connection::<()>("c=IN IP4 224.2.36.42/127/3/1\r\n").is_err());
+    }
+
+    #[test]
+    fn test_parse_connection_fqdn() {
+        let (tail, value) = parse_connection::<()>("c=IN IP4 host.example.com\r\n").unwrap();
+        assert_eq!(tail, "");
+        assert_eq!(value.address, ConnectionAddress::Fqdn("host.example.com"));
+    }
+
+    #[test]
+    fn test_connection_display() {
+        for input in ["c=IN IP4 224.2.36.42/127/3\r\n", "c=IN IP6 ::1\r\n"] {
+            let (_, value) = parse_connection::<()>(input).unwrap();
+            assert_eq!(value.to_string(), input);
+        }
+
+        let value = ConnectionInformation::new(
+            NetType::IN,
+            AddrType::IP6,
+            ConnectionAddress::Ip("ff15::101".parse().unwrap()),
+            Some(127),
+            Some(3),
+        );
+        assert_eq!(value.ttl, None);
+        assert_eq!(value.to_string(), "c=IN IP6 ff15::101/3\r\n");
+    }
+
+    #[test]
+    fn test_parse_connection_invalid_address() {
+        assert!(parse_connection::<()>("c=IN IP4 192.168.10.256\r\n").is_err());
+    }
+}
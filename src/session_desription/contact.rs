@@ -0,0 +1,194 @@
+use std::fmt;
+
+use nom::{
+    bytes::complete::tag,
+    character::complete::{line_ending, not_line_ending},
+    combinator::{map, opt, verify},
+    error::ParseError,
+    sequence::{preceded, terminated},
+    IResult, Parser,
+};
+
+use super::Rebind;
+
+/// Splits a contact value into its address and optional display name.
+///
+/// RFC 8866 allows both `j.doe@example.com (Jane Doe)` and `Jane Doe <j.doe@example.com>`.
+fn split_display_name(value: &str) -> (&str, Option<&str>) {
+    if let Some(rest) = value.strip_suffix(')') {
+        if let Some((address, name)) = rest.split_once('(') {
+            return (address.trim_end(), Some(name));
+        }
+    }
+    if let Some(rest) = value.strip_suffix('>') {
+        if let Some((name, address)) = rest.split_once('<') {
+            return (address, Some(name.trim_end()));
+        }
+    }
+    (value, None)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Email<'a> {
+    email: &'a str,
+}
+
+impl<'a> Email<'a> {
+    pub fn new(email: &'a str) -> Self {
+        Self { email }
+    }
+
+    /// Returns the raw value of the `e=` line, including any display name.
+    pub fn email(&self) -> &'a str {
+        self.email
+    }
+
+    /// Returns the email address without the display name.
+    pub fn address(&self) -> &'a str {
+        split_display_name(self.email).0
+    }
+
+    pub fn display_name(&self) -> Option<&'a str> {
+        split_display_name(self.email).1
+    }
+}
+
+impl Rebind for Email<'_> {
+    type Output<'b> = Email<'b>;
+
+    fn rebind<'b>(&self, strings: &mut dyn FnMut(&str) -> &'b str) -> Email<'b> {
+        Email {
+            email: strings(self.email),
+        }
+    }
+}
+
+impl fmt::Display for Email<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "e={}\r\n", self.email)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Phone<'a> {
+    phone: &'a str,
+}
+
+impl<'a> Phone<'a> {
+    pub fn new(phone: &'a str) -> Self {
+        Self { phone }
+    }
+
+    /// Returns the raw value of the `p=` line, including any display name.
+    pub fn phone(&self) -> &'a str {
+        self.phone
+    }
+
+    /// Returns the phone number without the display name.
+    pub fn number(&self) -> &'a str {
+        split_display_name(self.phone).0
+    }
+
+    pub fn display_name(&self) -> Option<&'a str> {
+        split_display_name(self.phone).1
+    }
+}
+
+impl Rebind for Phone<'_> {
+    type Output<'b> = Phone<'b>;
+
+    fn rebind<'b>(&self, strings: &mut dyn FnMut(&str) -> &'b str) -> Phone<'b> {
+        Phone {
+            phone: strings(self.phone),
+        }
+    }
+}
+
+impl fmt::Display for Phone<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "p={}\r\n", self.phone)
+    }
+}
+
+fn parse_contact<'i, E: ParseError<&'i str>>(
+    prefix: &'static str,
+) -> impl FnMut(&'i str) -> IResult<&'i str, &'i str, E> {
+    preceded(
+        tag(prefix),
+        terminated(
+            verify(not_line_ending, |s: &str| !s.is_empty()),
+            opt(line_ending),
+        ),
+    )
+}
+
+/// e=<email-address>
+/// e=j.doe@example.com (Jane Doe)
+/// see https://tools.ietf.org/html/rfc8866#section-5.6
+pub fn parse_email<'i, E: ParseError<&'i str>>(input: &'i str) -> IResult<&'i str, Email<'i>, E> {
+    map(parse_contact("e="), Email::new).parse(input)
+}
+
+/// p=<phone-number>
+/// p=+1 617 555-6011
+/// see https://tools.ietf.org/html/rfc8866#section-5.6
+pub fn parse_phone<'i, E: ParseError<&'i str>>(input: &'i str) -> IResult<&'i str, Phone<'i>, E> {
+    map(parse_contact("p="), Phone::new).parse(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_email() {
+        let (tail, value) = parse_email::<()>("e=j.doe@example.com\r\n").unwrap();
+        assert_eq!(tail, "");
+        assert_eq!(value.email(), "j.doe@example.com");
+        assert_eq!(value.address(), "j.doe@example.com");
+        assert_eq!(value.display_name(), None);
+    }
+
+    #[test]
+    fn test_parse_email_with_trailing_display_name() {
+        let input = "e=j.doe@example.com (Jane Doe)\r\n";
+        let (_, value) = parse_email::<()>(input).unwrap();
+        assert_eq!(value.email(), "j.doe@example.com (Jane Doe)");
+        assert_eq!(value.address(), "j.doe@example.com");
+        assert_eq!(value.display_name(), Some("Jane Doe"));
+        assert_eq!(value.to_string(), input);
+    }
+
+    #[test]
+    fn test_parse_email_with_leading_display_name() {
+        let (_, value) = parse_email::<()>("e=Jane Doe <j.doe@example.com>\r\n").unwrap();
+        assert_eq!(value.address(), "j.doe@example.com");
+        assert_eq!(value.display_name(), Some("Jane Doe"));
+    }
+
+    #[test]
+    fn test_parse_phone() {
+        let input = "p=+1 617 555-6011\r\n";
+        let (tail, value) = parse_phone::<()>(input).unwrap();
+        assert_eq!(tail, "");
+        assert_eq!(value.phone(), "+1 617 555-6011");
+        assert_eq!(value.number(), "+1 617 555-6011");
+        assert_eq!(value.display_name(), None);
+        assert_eq!(value.to_string(), input);
+    }
+
+    #[test]
+    fn test_parse_phone_with_display_name() {
+        let (_, value) = parse_phone::<()>("p=+1 617 555-6011 (Jane Doe)\r\n").unwrap();
+        assert_eq!(value.number(), "+1 617 555-6011");
+        assert_eq!(value.display_name(), Some("Jane Doe"));
+    }
+
+    #[test]
+    fn test_parse_empty_contact() {
+        assert!(parse_email::<()>("e=\r\n").is_err());
+        assert!(parse_phone::<()>("p=\r\n").is_err());
+    }
+}
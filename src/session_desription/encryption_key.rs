@@ -0,0 +1,117 @@
+use std::fmt;
+
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{line_ending, not_line_ending},
+    combinator::{map, opt, verify},
+    error::ParseError,
+    sequence::{preceded, terminated},
+    IResult, Parser,
+};
+
+use super::Rebind;
+
+/// The encryption key of an `k=` line.
+///
+/// The `k=` line is obsolete and MUST NOT be used by new implementations, it is only kept
+/// for interoperability with legacy devices.
+///
+/// see https://tools.ietf.org/html/rfc4566#section-5.12
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EncryptionKey<'a> {
+    /// `k=clear:<encryption key>`
+    Clear(&'a str),
+    /// `k=base64:<encoded encryption key>`
+    Base64(&'a str),
+    /// `k=uri:<URI to obtain key>`
+    Uri(&'a str),
+    /// `k=prompt`
+    Prompt,
+}
+
+impl Rebind for EncryptionKey<'_> {
+    type Output<'b> = EncryptionKey<'b>;
+
+    fn rebind<'b>(&self, strings: &mut dyn FnMut(&str) -> &'b str) -> EncryptionKey<'b> {
+        match self {
+            EncryptionKey::Clear(key) => EncryptionKey::Clear(strings(key)),
+            EncryptionKey::Base64(key) => EncryptionKey::Base64(strings(key)),
+            EncryptionKey::Uri(uri) => EncryptionKey::Uri(strings(uri)),
+            EncryptionKey::Prompt => EncryptionKey::Prompt,
+        }
+    }
+}
+
+impl fmt::Display for EncryptionKey<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EncryptionKey::Clear(key) => write!(f, "k=clear:{}\r\n", key),
+            EncryptionKey::Base64(key) => write!(f, "k=base64:{}\r\n", key),
+            EncryptionKey::Uri(uri) => write!(f, "k=uri:{}\r\n", uri),
+            EncryptionKey::Prompt => write!(f, "k=prompt\r\n"),
+        }
+    }
+}
+
+fn parse_key_value<'i, E: ParseError<&'i str>>(input: &'i str) -> IResult<&'i str, &'i str, E> {
+    verify(not_line_ending, |s: &str| !s.is_empty()).parse(input)
+}
+
+/// k=<method>
+/// k=<method>:<encryption key>
+/// see https://tools.ietf.org/html/rfc8866#section-5.12
+pub fn parse_encryption_key<'i, E: ParseError<&'i str>>(
+    input: &'i str,
+) -> IResult<&'i str, EncryptionKey<'i>, E> {
+    preceded(
+        tag("k="),
+        terminated(
+            alt((
+                map(
+                    preceded(tag("clear:"), parse_key_value),
+                    EncryptionKey::Clear,
+                ),
+                map(
+                    preceded(tag("base64:"), parse_key_value),
+                    EncryptionKey::Base64,
+                ),
+                map(preceded(tag("uri:"), parse_key_value), EncryptionKey::Uri),
+                map(tag("prompt"), |_| EncryptionKey::Prompt),
+            )),
+            opt(line_ending),
+        ),
+    )
+    .parse(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_encryption_key() {
+        let cases = [
+            ("k=clear:secret\r\n", EncryptionKey::Clear("secret")),
+            ("k=base64:c2VjcmV0\r\n", EncryptionKey::Base64("c2VjcmV0")),
+            (
+                "k=uri:https://example.com/key\r\n",
+                EncryptionKey::Uri("https://example.com/key"),
+            ),
+            ("k=prompt\r\n", EncryptionKey::Prompt),
+        ];
+        for (input, expected) in cases {
+            let (tail, value) = parse_encryption_key::<()>(input).unwrap();
+            assert_eq!(tail, "");
+            assert_eq!(value, expected);
+            assert_eq!(value.to_string(), input);
+        }
+    }
+
+    #[test]
+    fn test_parse_encryption_key_unknown_method() {
+        assert!(parse_encryption_key::<()>("k=rot13:secret\r\n").is_err());
+        assert!(parse_encryption_key::<()>("k=clear:\r\n").is_err());
+    }
+}
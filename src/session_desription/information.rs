@@ -0,0 +1,88 @@
+use std::fmt;
+
+use nom::{
+    bytes::complete::tag,
+    character::complete::{line_ending, not_line_ending},
+    combinator::{map, opt},
+    error::ParseError,
+    sequence::{preceded, terminated},
+    IResult, Parser,
+};
+
+use super::Rebind;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SessionInformation<'a> {
+    information: &'a str,
+}
+
+impl<'a> SessionInformation<'a> {
+    pub fn new(information: &'a str) -> Self {
+        Self { information }
+    }
+
+    pub fn information(&self) -> &'a str {
+        self.information
+    }
+}
+
+impl Rebind for SessionInformation<'_> {
+    type Output<'b> = SessionInformation<'b>;
+
+    fn rebind<'b>(&self, strings: &mut dyn FnMut(&str) -> &'b str) -> SessionInformation<'b> {
+        SessionInformation {
+            information: strings(self.information),
+        }
+    }
+}
+
+impl fmt::Display for SessionInformation<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "i={}\r\n", self.information)
+    }
+}
+
+/// Parses the session or media information from the given input string.
+///
+/// RFC-8866 defines it as `i=<session description>` where
+/// - There MUST be at most one session-level "i=" line per session description,
+///   and at most one media-level "i=" line per media. [1]
+///
+/// [1]: https://tools.ietf.org/html/rfc8866#section-5.4
+pub fn parse_information<'i, E: ParseError<&'i str>>(
+    input: &'i str,
+) -> IResult<&'i str, SessionInformation<'i>, E> {
+    map(
+        preceded(tag("i="), terminated(not_line_ending, opt(line_ending))),
+        SessionInformation::new,
+    )
+    .parse(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_information() {
+        let input = "i=A Seminar on the session description protocol\r\n";
+        let (tail, result) = parse_information::<()>(input).unwrap();
+        assert_eq!(tail, "");
+        assert_eq!(
+            result.information(),
+            "A Seminar on the session description protocol"
+        );
+        assert_eq!(result.to_string(), input);
+    }
+
+    #[test]
+    fn test_parse_information_utf8() {
+        let input = "i=Séminaire sur le protocole\r\n";
+        let (_, result) = parse_information::<()>(input).unwrap();
+        assert_eq!(
+            result,
+            SessionInformation::new("Séminaire sur le protocole")
+        );
+    }
+}
@@ -1,12 +1,71 @@
+mod address;
+#[cfg(test)]
+mod arbitrary;
+mod bandwidth;
+mod connection;
+mod contact;
+mod encryption_key;
+mod information;
+mod negotiate;
+mod normalize;
+mod ordering;
 mod origin;
+mod owned;
+mod role;
 mod session_name;
+mod stream;
+mod uri;
+mod verbatim;
 mod version;
-use std::net::{IpAddr, Ipv4Addr};
+pub(crate) use address::parse_connection_address;
+pub use address::ConnectionAddress;
+use bandwidth::parse_bandwidth;
+pub use bandwidth::Bandwidth;
+use connection::parse_connection;
+pub use connection::ConnectionInformation;
+use contact::{parse_email, parse_phone};
+pub use contact::{Email, Phone};
+use encryption_key::parse_encryption_key;
+pub use encryption_key::EncryptionKey;
+use information::parse_information;
+pub use information::SessionInformation;
+use std::fmt;
+
+use nom::{
+    character::complete::{char, line_ending, not_line_ending, satisfy},
+    combinator::{all_consuming, opt},
+    error::{Error, ParseError},
+    sequence::{separated_pair, terminated},
+    IResult, Parser,
+};
+use ordering::validate_ordering;
+use origin::parse_origin;
+pub use origin::{AddrType, NetType, Origin, OriginBuilder};
+pub use owned::OwnedSessionDescription;
+pub(crate) use owned::Rebind;
+pub use role::Role;
+pub use session_name::SessionName;
+pub use stream::SdpStreamParser;
+use uri::parse_uri;
+pub use uri::Uri;
+pub(crate) use verbatim::{write_line, VerbatimLines};
+use version::parse_version;
+pub use version::Version;
 
-use nom::{character::complete::alpha1, combinator::peek, error::ParseError, IResult};
-use origin::{parse_origin, AddrType, NetType, Origin};
-use session_name::SessionName;
-use version::{parse_version, Version};
+use crate::attribute::{
+    parse_attribute, parse_untyped_attribute, Attribute, AttributeList, Direction, Fingerprint,
+    Group, MsidSemantic,
+};
+use crate::error::{offset_in, SdpError};
+use crate::media_description::{parse_media, MediaDescription};
+use crate::options::ParseOptions;
+use crate::time_description::{
+    parse_repeat_times, parse_time, parse_time_zones, TimeDescription, TimeZone,
+};
+use crate::{
+    get_media_description_chunk, get_session_description_chunk, get_time_description_chunk,
+    skip_preamble, split_line, SDPLevel,
+};
 
 #[derive(Debug)]
 enum SessionDescriptionKeys {
@@ -14,126 +73,2016 @@ enum SessionDescriptionKeys {
     Origin,
     SessionName,
     SessionInformation,
-    URI,
+    Uri,
     EmailAddress,
     PhoneNumber,
     ConnectionInformation,
     BandwidthInformation,
     EncryptionKey, // To be discarded
     Attribute,
+    TimeDescription,
+    RepeatTimes,
+    TimeZone,
+    MediaDescription,
+    Unknown,
 }
 
-#[derive(Debug)]
-struct SessionDescription<'a> {
+/// A parsed SDP session description.
+///
+/// Parsing is zero-copy: every piece of text in the description (names, addresses, attribute
+/// values, ...) is a `&'a str` slice of the input buffer, so the description cannot outlive
+/// the text it was parsed from. The only allocations made while parsing are the `Vec`s that
+/// hold repeated lines such as `a=`, `b=` and `m=`.
+///
+/// With the `serde` feature enabled the description implements `Serialize` and `Deserialize`;
+/// deserializing borrows from the serialized data too.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SessionDescription<'a> {
     version: Version,
+    #[cfg_attr(feature = "serde", serde(borrow))]
     origin: Origin<'a>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
     session_name: SessionName<'a>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    information: Option<SessionInformation<'a>>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    uri: Option<Uri<'a>>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    emails: Vec<Email<'a>>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    phones: Vec<Phone<'a>>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    connection: Option<ConnectionInformation<'a>>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    bandwidths: Vec<Bandwidth<'a>>,
+    time_descriptions: Vec<TimeDescription>,
+    time_zones: Vec<TimeZone>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    encryption_key: Option<EncryptionKey<'a>>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    attributes: AttributeList<'a>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    media: Vec<MediaDescription<'a>>,
+    duplicate_lines: Vec<char>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    verbatim_lines: VerbatimLines<'a>,
 }
 
 impl<'a> SessionDescription<'a> {
-    pub fn new(version: Version, origin: Origin<'a>, session_name: SessionName<'a>) -> Self {
+    pub fn new(
+        version: Version,
+        origin: Origin<'a>,
+        session_name: SessionName<'a>,
+        time_description: TimeDescription,
+    ) -> Self {
         Self {
             version,
             origin,
             session_name,
+            information: None,
+            uri: None,
+            emails: Vec::new(),
+            phones: Vec::new(),
+            connection: None,
+            bandwidths: Vec::new(),
+            time_descriptions: vec![time_description],
+            time_zones: Vec::new(),
+            encryption_key: None,
+            attributes: AttributeList::new(),
+            media: Vec::new(),
+            duplicate_lines: Vec::new(),
+            verbatim_lines: VerbatimLines::default(),
         }
     }
 
-    fn from_str(s: &'a str) -> Result<Self, ()> {
-        let mut version: Version = { Version::new(0) };
-        let mut origin: Origin = {
-            Origin::new(
-                "",
-                "",
-                0,
-                NetType::IN,
-                AddrType::IP4,
-                IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0)),
-            )
-        };
-        let mut session_name: SessionName = { SessionName::new("") };
-        let tail: &str = s;
-        while !tail.is_empty() {
-            let (mut tail, key) = peek_key::<()>(tail).unwrap();
-            match key {
-                SessionDescriptionKeys::Version => {
-                    let (rem, v) = parse_version::<()>(tail).unwrap();
-                    version = v;
-                    tail = rem;
-                    if tail.is_empty() {
-                        break;
+    /// Returns the protocol version (`v=`) of the session.
+    pub fn version(&self) -> &Version {
+        &self.version
+    }
+
+    /// Returns the originator and session identifier (`o=`) of the session.
+    pub fn origin(&self) -> &Origin<'a> {
+        &self.origin
+    }
+
+    /// Returns the session name (`s=`) of the session.
+    pub fn session_name(&self) -> &SessionName<'a> {
+        &self.session_name
+    }
+
+    /// Returns the session information (`i=`), if present.
+    pub fn information(&self) -> Option<&SessionInformation<'a>> {
+        self.information.as_ref()
+    }
+
+    /// Returns the URI of the session (`u=`), if present.
+    pub fn uri(&self) -> Option<&Uri<'a>> {
+        self.uri.as_ref()
+    }
+
+    /// Returns the email addresses (`e=`) of the person responsible for the session.
+    pub fn emails(&self) -> &[Email<'a>] {
+        &self.emails
+    }
+
+    /// Returns the phone numbers (`p=`) of the person responsible for the session.
+    pub fn phones(&self) -> &[Phone<'a>] {
+        &self.phones
+    }
+
+    /// Returns the session-level connection data (`c=`), if present.
+    pub fn connection(&self) -> Option<&ConnectionInformation<'a>> {
+        self.connection.as_ref()
+    }
+
+    /// Returns the session-level bandwidth lines (`b=`), in the order they appeared.
+    pub fn bandwidths(&self) -> &[Bandwidth<'a>] {
+        &self.bandwidths
+    }
+
+    /// Returns the first time description (`t=`) of the session. Parsing always gives one,
+    /// but a description deserialized with the `serde` feature may have none.
+    pub fn time_description(&self) -> Option<&TimeDescription> {
+        self.time_descriptions.first()
+    }
+
+    /// Returns all time descriptions (`t=` and their `r=` lines) of the session, in order.
+    /// There is always at least one.
+    pub fn time_descriptions(&self) -> &[TimeDescription] {
+        &self.time_descriptions
+    }
+
+    /// Returns the time zone adjustments (`z=`) of a repeated session, in order.
+    pub fn time_zones(&self) -> &[TimeZone] {
+        &self.time_zones
+    }
+
+    /// Returns the session-level encryption key (`k=`), if present and kept with
+    /// [`ParseOptions::keep_encryption_key`].
+    pub fn encryption_key(&self) -> Option<&EncryptionKey<'a>> {
+        self.encryption_key.as_ref()
+    }
+
+    /// Returns the session-level attributes (`a=`), in the order they appeared.
+    pub fn attributes(&self) -> &[Attribute<'a>] {
+        &self.attributes
+    }
+
+    /// Returns the session-level attributes for modification.
+    pub fn attributes_mut(&mut self) -> &mut AttributeList<'a> {
+        &mut self.attributes
+    }
+
+    /// Returns the character set of the text fields (`a=charset:`), if given.
+    pub fn charset(&self) -> Option<&'a str> {
+        self.attributes
+            .iter()
+            .find_map(|attribute| match attribute {
+                Attribute::Charset(charset) => Some(*charset),
+                _ => None,
+            })
+    }
+
+    /// Returns the ICE options of the session (`a=ice-options:`), in the order they appeared.
+    pub fn ice_options(&self) -> &[&'a str] {
+        self.attributes
+            .iter()
+            .find_map(|attribute| match attribute {
+                Attribute::IceOptions(options) => Some(options.as_slice()),
+                _ => None,
+            })
+            .unwrap_or_default()
+    }
+
+    /// Checks that the session name is valid in the session charset (`a=charset:`, UTF-8 if
+    /// absent), see [`SessionName::validate_char_set`].
+    pub fn validate_session_name(&self) -> Result<(), SdpError> {
+        let charset = self.charset().unwrap_or_default();
+        if !session_name::is_supported_char_set(charset) {
+            return Err(SdpError::UnsupportedCharset(charset.to_string()));
+        }
+        if !self.session_name.validate_char_set(charset) {
+            return Err(SdpError::InvalidSessionName);
+        }
+        Ok(())
+    }
+
+    /// Checks the session description against the MUST rules of RFC 8866, returning every
+    /// violation found rather than only the first one.
+    ///
+    /// The checks are: the version is `0`; the parsed input had a single `v=`, `o=`, `s=`,
+    /// `i=`, `u=` and `c=` line at session level and a single `i=` line per media section
+    /// (lenient parsing keeps the first of them); the session name is not empty and valid
+    /// in the session charset; there is at least one `t=` line; a `c=` line is given at session
+    /// level or in every media section; every address matches its `<addrtype>`; no two
+    /// `a=extmap:` of a media section share an id; and every `a=group:` references existing
+    /// media.
+    pub fn validate(&self) -> Result<(), Vec<SdpError>> {
+        let mut errors = Vec::new();
+        if self.version.version() != 0 {
+            errors.push(SdpError::UnsupportedVersion(self.version.version()));
+        }
+        errors.extend(
+            self.duplicate_lines
+                .iter()
+                .copied()
+                .map(SdpError::DuplicateLine),
+        );
+        if self.session_name.name().is_empty() {
+            errors.push(SdpError::EmptySessionName);
+        } else if let Err(err) = self.validate_session_name() {
+            errors.push(err);
+        }
+        if !self
+            .origin
+            .addrtype()
+            .matches(self.origin.unicast_address())
+        {
+            errors.push(SdpError::AddressTypeMismatch);
+        }
+        if self.time_descriptions.is_empty() {
+            errors.push(SdpError::MissingRequiredLine("t="));
+        }
+        if self.connection.is_none() && self.media.iter().any(|m| m.connection().is_none()) {
+            errors.push(SdpError::MissingRequiredLine("c="));
+        }
+        let connections = self
+            .connection
+            .iter()
+            .chain(self.media.iter().flat_map(|m| m.connections()));
+        for connection in connections {
+            if !connection.addrtype().matches(connection.address()) {
+                errors.push(SdpError::AddressTypeMismatch);
+            }
+        }
+        errors.extend(
+            self.media
+                .iter()
+                .flat_map(|media| media.duplicate_extmap_ids())
+                .map(SdpError::DuplicateExtmapId),
+        );
+        errors.extend(
+            self.unknown_media_ids()
+                .map(|identifier| SdpError::UnknownMediaId(identifier.to_string())),
+        );
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Returns the session-level attributes named `name`, in the order they appeared.
+    pub fn attributes_by_name<'s>(
+        &'s self,
+        name: &'s str,
+    ) -> impl Iterator<Item = &'s Attribute<'a>> + 's {
+        self.attributes
+            .iter()
+            .filter(move |attribute| attribute.name() == name)
+    }
+
+    /// Returns the session-level DTLS certificate fingerprint (`a=fingerprint:`), if present.
+    pub fn fingerprint(&self) -> Option<&Fingerprint<'a>> {
+        self.attributes
+            .iter()
+            .find_map(|attribute| match attribute {
+                Attribute::Fingerprint(fingerprint) => Some(fingerprint),
+                _ => None,
+            })
+    }
+
+    /// Returns the session-level DTLS association identifier (`a=tls-id:`, or the older
+    /// `a=dtls-id:`), if present. RFC 8842 defines it for media sections only, this covers
+    /// the endpoints that set it for the whole session.
+    pub fn tls_id(&self) -> Option<&'a str> {
+        self.attributes
+            .iter()
+            .find_map(|attribute| match attribute {
+                Attribute::TlsId(id) => Some(*id),
+                _ => None,
+            })
+            .or_else(|| {
+                self.attributes
+                    .iter()
+                    .find_map(|attribute| match attribute {
+                        Attribute::DtlsId(id) => Some(*id),
+                        _ => None,
+                    })
+            })
+    }
+
+    /// Returns the session-level RTSP control URL (`a=control:`), e.g. `*` or an absolute
+    /// `rtsp://` URL, if present.
+    pub fn control(&self) -> Option<&'a str> {
+        self.attributes
+            .iter()
+            .find_map(|attribute| match attribute {
+                Attribute::Control(control) => Some(*control),
+                _ => None,
+            })
+    }
+
+    /// Returns the session-level media direction (`a=sendrecv` etc.), if present.
+    pub fn direction(&self) -> Option<Direction> {
+        self.attributes
+            .iter()
+            .find_map(|attribute| match attribute {
+                Attribute::Direction(direction) => Some(*direction),
+                _ => None,
+            })
+    }
+
+    /// Returns `true` if no more candidates will be trickled for any media of the session
+    /// (session-level `a=end-of-candidates`).
+    pub fn end_of_candidates(&self) -> bool {
+        self.attributes.iter().any(
+            |attribute| matches!(attribute, Attribute::Property(name) if *name == "end-of-candidates"),
+        )
+    }
+
+    /// Returns the session-level media groupings (`a=group:`), in the order they appeared.
+    pub fn groups(&self) -> impl Iterator<Item = &Group<'a>> {
+        self.attributes
+            .iter()
+            .filter_map(|attribute| match attribute {
+                Attribute::Group(group) => Some(group),
+                _ => None,
+            })
+    }
+
+    /// Returns the session-level media stream semantics (`a=msid-semantic:`), in the order
+    /// they appeared.
+    pub fn msid_semantics(&self) -> impl Iterator<Item = &MsidSemantic<'a>> {
+        self.attributes
+            .iter()
+            .filter_map(|attribute| match attribute {
+                Attribute::MsidSemantic(semantic) => Some(semantic),
+                _ => None,
+            })
+    }
+
+    /// Returns the session-level lines with a `<type>` this crate does not know about, as
+    /// `(<type>, <value>)` pairs in the order they appeared.
+    ///
+    /// Such lines are kept rather than rejected so that proprietary extensions survive a
+    /// parse and re-serialization, in place among the `a=` lines.
+    pub fn unknown_lines(&self) -> &[(char, &'a str)] {
+        self.attributes.unknown_lines()
+    }
+
+    /// Returns the media descriptions (`m=` sections) of the session, in order.
+    pub fn media(&self) -> &[MediaDescription<'a>] {
+        &self.media
+    }
+
+    /// Returns the media descriptions for modification, e.g. to
+    /// [reject](MediaDescription::reject) some of them.
+    pub fn media_mut(&mut self) -> &mut [MediaDescription<'a>] {
+        &mut self.media
+    }
+
+    /// Keeps only the media descriptions for which `f` returns `true`, in their order.
+    ///
+    /// An answer must have as many media sections as its offer; reject the unwanted ones with
+    /// [`MediaDescription::reject`] rather than remove them when answering.
+    pub fn retain_media(&mut self, f: impl FnMut(&MediaDescription<'a>) -> bool) {
+        self.media.retain(f);
+    }
+
+    /// Returns the media descriptions whose `<media>` is `kind`, e.g. `audio` or `video`, in
+    /// order.
+    pub fn media_by_type<'s>(
+        &'s self,
+        kind: &'s str,
+    ) -> impl Iterator<Item = &'s MediaDescription<'a>> + 's {
+        self.media.iter().filter(move |media| media.media() == kind)
+    }
+
+    /// Checks that the lines of `s` appear in the order RFC 8866 requires, returning
+    /// [`SdpError::OutOfOrderLine`] for the first one that does not.
+    ///
+    /// Parsing runs this check unless [`ParseOptions::allow_out_of_order`] is set.
+    pub fn validate_ordering(s: &str) -> Result<(), SdpError> {
+        validate_ordering(s)
+    }
+
+    pub(crate) fn from_str(s: &'a str) -> Result<Self, SdpError> {
+        Self::from_str_with_options(s, &ParseOptions::default())
+    }
+
+    pub(crate) fn from_str_with_options(
+        s: &'a str,
+        options: &ParseOptions,
+    ) -> Result<Self, SdpError> {
+        let mut version: Option<Version> = None;
+        let mut origin: Option<Origin> = None;
+        let mut session_name: Option<SessionName> = None;
+        let mut information: Option<SessionInformation> = None;
+        let mut uri: Option<Uri> = None;
+        let mut emails: Vec<Email> = Vec::new();
+        let mut phones: Vec<Phone> = Vec::new();
+        let mut connection: Option<ConnectionInformation> = None;
+        let mut bandwidths: Vec<Bandwidth> = Vec::new();
+        let mut time_descriptions: Vec<TimeDescription> = Vec::new();
+        let mut time_zones: Vec<TimeZone> = Vec::new();
+        let mut encryption_key: Option<EncryptionKey> = None;
+        let mut attributes = AttributeList::new();
+        let mut media: Vec<MediaDescription> = Vec::new();
+        let mut duplicate_lines: Vec<char> = Vec::new();
+        let mut verbatim_lines = VerbatimLines::default();
+        let mut level = SDPLevel::SessionLevel;
+        // Offsets in errors stay relative to `s`, since the preamble is a prefix of it.
+        let input = skip_preamble(s);
+        let mut next: &str = input;
+        while !next.is_empty() {
+            let (chunk, rest) = match level {
+                SDPLevel::SessionLevel => get_session_description_chunk(next),
+                SDPLevel::TimeDescriptionLevel => get_time_description_chunk(next),
+                SDPLevel::MediaDescriptionLevel => get_media_description_chunk(next),
+            };
+            for line in chunk.split_inclusive('\n') {
+                let value = line.trim_end_matches('\n').trim_end_matches('\r');
+                let (line_type, _) = split_line(value, offset_in(s, line).unwrap_or_default())?;
+                let duplicates = duplicate_lines.len();
+                match SessionDescriptionKeys::from(line_type) {
+                    SessionDescriptionKeys::Version => {
+                        let v = parse_line(s, line, parse_version)?;
+                        if v.version() != 0 {
+                            return Err(SdpError::UnsupportedVersion(v.version()));
+                        }
+                        if version.is_some() {
+                            duplicate_lines.push(line_type);
+                        } else {
+                            version = Some(v);
+                        }
                     }
-                }
-                SessionDescriptionKeys::Origin => {
-                    let (rem, o) = parse_origin::<()>(tail).unwrap();
-                    origin = o;
-                    tail = rem;
-                    if tail.is_empty() {
-                        break;
+                    SessionDescriptionKeys::Origin => {
+                        let o = parse_line(s, line, parse_origin)?;
+                        if !o.addrtype().matches(o.unicast_address()) {
+                            return Err(SdpError::AddressTypeMismatch);
+                        }
+                        if origin.is_some() {
+                            duplicate_lines.push(line_type);
+                        } else {
+                            origin = Some(o);
+                        }
+                    }
+                    SessionDescriptionKeys::SessionName => {
+                        let n = parse_line(s, line, session_name::parse_session_name)?;
+                        if n.name().is_empty() {
+                            return Err(SdpError::EmptySessionName);
+                        }
+                        if session_name.is_some() {
+                            duplicate_lines.push(line_type);
+                        } else {
+                            session_name = Some(n);
+                        }
+                    }
+                    SessionDescriptionKeys::SessionInformation => {
+                        let i = parse_line(s, line, parse_information)?;
+                        match media.last_mut() {
+                            Some(m) if m.information().is_some() => duplicate_lines.push(line_type),
+                            Some(m) => m.set_information(i),
+                            None if information.is_some() => duplicate_lines.push(line_type),
+                            None => information = Some(i),
+                        }
+                    }
+                    SessionDescriptionKeys::Uri => {
+                        let u = parse_line(s, line, parse_uri)?;
+                        if uri.is_some() {
+                            duplicate_lines.push(line_type);
+                        } else {
+                            uri = Some(u);
+                        }
+                    }
+                    SessionDescriptionKeys::EmailAddress => {
+                        emails.push(parse_line(s, line, parse_email)?);
+                    }
+                    SessionDescriptionKeys::PhoneNumber => {
+                        phones.push(parse_line(s, line, parse_phone)?);
+                    }
+                    SessionDescriptionKeys::ConnectionInformation => {
+                        let c = parse_line(s, line, parse_connection)?;
+                        if !c.addrtype().matches(c.address()) {
+                            return Err(SdpError::AddressTypeMismatch);
+                        }
+                        match media.last_mut() {
+                            Some(m) => m.push_connection(c),
+                            None if connection.is_some() => duplicate_lines.push(line_type),
+                            None => connection = Some(c),
+                        }
+                    }
+                    SessionDescriptionKeys::BandwidthInformation => {
+                        let b = parse_line(s, line, parse_bandwidth)?;
+                        match media.last_mut() {
+                            Some(m) => m.push_bandwidth(b),
+                            None => bandwidths.push(b),
+                        }
+                    }
+                    SessionDescriptionKeys::TimeDescription => {
+                        time_descriptions.push(parse_line(s, line, parse_time)?);
+                    }
+                    SessionDescriptionKeys::RepeatTimes => {
+                        let r = parse_line(s, line, parse_repeat_times)?;
+                        time_descriptions
+                            .last_mut()
+                            .ok_or(SdpError::MissingRequiredLine("t="))?
+                            .push_repeat_times(r);
+                    }
+                    SessionDescriptionKeys::TimeZone => {
+                        time_zones.extend(parse_line(s, line, parse_time_zones)?);
+                    }
+                    SessionDescriptionKeys::MediaDescription => {
+                        media.push(parse_line(s, line, parse_media)?);
+                    }
+                    SessionDescriptionKeys::EncryptionKey => {
+                        let k = parse_line(s, line, parse_encryption_key)?;
+                        if options.keep_encryption_key {
+                            match media.last_mut() {
+                                Some(m) => m.set_encryption_key(k),
+                                None => encryption_key = Some(k),
+                            }
+                        }
+                    }
+                    SessionDescriptionKeys::Attribute => {
+                        let a = match parse_line(s, line, parse_attribute) {
+                            Err(_) if options.allow_malformed_attributes => {
+                                parse_line(s, line, parse_untyped_attribute)?
+                            }
+                            result => result?,
+                        };
+                        let line = options.preserve_attribute_lines.then_some(line);
+                        match media.last_mut() {
+                            Some(m) => m.push_attribute(a, line),
+                            None => attributes.push_line(a, line),
+                        }
+                    }
+                    SessionDescriptionKeys::Unknown => {
+                        let (t, value) = parse_line(s, line, parse_unknown_line)?;
+                        let line = options.preserve_attribute_lines.then_some(line);
+                        match media.last_mut() {
+                            _ if !options.preserve_unknown_lines => {}
+                            Some(m) => m.push_unknown_line(t, value, line),
+                            None => attributes.push_unknown_line(t, value, line),
+                        }
                     }
                 }
-                SessionDescriptionKeys::SessionName => {
-                    let (rem, s) = session_name::parse_session_name::<()>(tail).unwrap();
-                    session_name = s;
-                    tail = rem;
-                    if tail.is_empty() {
-                        break;
+                // The other lines are kept where the model holds them, unless they were
+                // dropped as duplicates or as an encryption key not to keep.
+                if options.preserve_attribute_lines && duplicate_lines.len() == duplicates {
+                    match (line_type, media.last_mut()) {
+                        ('k', _) if !options.keep_encryption_key => {}
+                        ('m' | 'i' | 'c' | 'b' | 'k', Some(m)) => m.push_verbatim_line(line),
+                        (
+                            'v' | 'o' | 's' | 'i' | 'u' | 'e' | 'p' | 'c' | 'b' | 't' | 'r' | 'z'
+                            | 'k',
+                            _,
+                        ) => verbatim_lines.push(line),
+                        _ => {}
                     }
                 }
-                _ => unimplemented!("key not implemented"),
             }
+            next = rest;
+            level = match level {
+                SDPLevel::SessionLevel => SDPLevel::TimeDescriptionLevel,
+                _ => SDPLevel::MediaDescriptionLevel,
+            };
+        }
+        let version = version.ok_or(SdpError::MissingRequiredLine("v="))?;
+        let origin = origin.ok_or(SdpError::MissingRequiredLine("o="))?;
+        let session_name = session_name.ok_or(SdpError::MissingRequiredLine("s="))?;
+        if time_descriptions.is_empty() {
+            if !options.allow_missing_time {
+                return Err(SdpError::MissingRequiredLine("t="));
+            }
+            time_descriptions.push(TimeDescription::new(0, 0));
+        }
+        if !options.allow_out_of_order {
+            validate_ordering(input)?;
         }
-        Ok(SessionDescription::new(version, origin, session_name))
+        let session = SessionDescription {
+            version,
+            origin,
+            session_name,
+            information,
+            uri,
+            emails,
+            phones,
+            connection,
+            bandwidths,
+            time_descriptions,
+            time_zones,
+            encryption_key,
+            attributes,
+            media,
+            duplicate_lines,
+            verbatim_lines,
+        };
+        session.validate_groups()?;
+        Ok(session)
     }
+
+    /// Checks that every identification tag referenced by an `a=group:` line is carried by
+    /// some `m=` section.
+    ///
+    /// see https://tools.ietf.org/html/rfc5888#section-5
+    fn validate_groups(&self) -> Result<(), SdpError> {
+        match self.unknown_media_ids().next() {
+            Some(identifier) => Err(SdpError::UnknownMediaId(identifier.to_string())),
+            None => Ok(()),
+        }
+    }
+
+    /// Returns the identification tags referenced by an `a=group:` line that no `m=` section
+    /// carries.
+    fn unknown_media_ids(&self) -> impl Iterator<Item = &'a str> + '_ {
+        self.groups()
+            .flat_map(|group| group.identifiers().iter().copied())
+            .filter(|identifier| !self.media.iter().any(|m| m.mid() == Some(*identifier)))
+    }
+}
+
+/// Parses a session description with the default [`ParseOptions`], like [`crate::parse`].
+///
+/// ```
+/// use sdp_parser::SessionDescription;
+///
+/// let input = "v=0\r\no=- 1 1 IN IP4 10.0.0.1\r\ns=-\r\nt=0 0\r\n";
+/// let session: SessionDescription = input.try_into().unwrap();
+/// assert_eq!(session.origin().session_id(), "1");
+/// ```
+impl<'a> TryFrom<&'a str> for SessionDescription<'a> {
+    type Error = SdpError;
+
+    fn try_from(s: &'a str) -> Result<Self, Self::Error> {
+        Self::from_str(s)
+    }
+}
+
+impl Rebind for SessionDescription<'_> {
+    type Output<'b> = SessionDescription<'b>;
+
+    fn rebind<'b>(&self, strings: &mut dyn FnMut(&str) -> &'b str) -> SessionDescription<'b> {
+        SessionDescription {
+            version: self.version.clone(),
+            origin: self.origin.rebind(strings),
+            session_name: self.session_name.rebind(strings),
+            information: self.information.rebind(strings),
+            uri: self.uri.rebind(strings),
+            emails: self.emails.rebind(strings),
+            phones: self.phones.rebind(strings),
+            connection: self.connection.rebind(strings),
+            bandwidths: self.bandwidths.rebind(strings),
+            time_descriptions: self.time_descriptions.clone(),
+            time_zones: self.time_zones.clone(),
+            encryption_key: self.encryption_key.rebind(strings),
+            attributes: self.attributes.rebind(strings),
+            media: self.media.rebind(strings),
+            duplicate_lines: self.duplicate_lines.clone(),
+            verbatim_lines: self.verbatim_lines.rebind(strings),
+        }
+    }
+}
+
+impl fmt::Display for SessionDescription<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_lines(f, &|f, attributes| write!(f, "{}", attributes), true)
+    }
+}
+
+impl SessionDescription<'_> {
+    /// Writes the lines of the session description in RFC 8866 order, the `a=` lines of each
+    /// section (and the unknown lines among them) with `write_attributes`.
+    pub(crate) fn write_lines(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        write_attributes: &impl Fn(&mut fmt::Formatter<'_>, &AttributeList<'_>) -> fmt::Result,
+        verbatim: bool,
+    ) -> fmt::Result {
+        if !(verbatim && self.verbatim_lines.write(f)?) {
+            self.write_typed_lines(f)?;
+        }
+        write_attributes(f, &self.attributes)?;
+        for media in &self.media {
+            media.write_lines(f, write_attributes, verbatim)?;
+        }
+        Ok(())
+    }
+
+    /// Writes the session-level lines other than the attributes and unknown lines.
+    fn write_typed_lines(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}{}", self.version, self.origin, self.session_name)?;
+        if let Some(information) = &self.information {
+            write!(f, "{}", information)?;
+        }
+        if let Some(uri) = &self.uri {
+            write!(f, "{}", uri)?;
+        }
+        for email in &self.emails {
+            write!(f, "{}", email)?;
+        }
+        for phone in &self.phones {
+            write!(f, "{}", phone)?;
+        }
+        if let Some(connection) = &self.connection {
+            write!(f, "{}", connection)?;
+        }
+        for bandwidth in &self.bandwidths {
+            write!(f, "{}", bandwidth)?;
+        }
+        for time_description in &self.time_descriptions {
+            write!(f, "{}", time_description)?;
+        }
+        if !self.time_zones.is_empty() {
+            write!(f, "z=")?;
+            for (index, time_zone) in self.time_zones.iter().enumerate() {
+                let separator = if index == 0 { "" } else { " " };
+                write!(f, "{}{}", separator, time_zone)?;
+            }
+            write!(f, "\r\n")?;
+        }
+        if let Some(encryption_key) = &self.encryption_key {
+            write!(f, "{}", encryption_key)?;
+        }
+        Ok(())
+    }
+}
+
+/// <type>=<value>
+/// x=vendor specific value
+fn parse_unknown_line<'i, E: ParseError<&'i str>>(
+    input: &'i str,
+) -> IResult<&'i str, (char, &'i str), E> {
+    terminated(
+        separated_pair(
+            satisfy(|c| c.is_ascii_alphabetic()),
+            char('='),
+            not_line_ending,
+        ),
+        opt(line_ending),
+    )
+    .parse(input)
 }
 
-fn peek_key<'i, E: ParseError<&'i str>>(
+/// Parses the whole of `line`, a line of `input` along with its line ending, with `parser`.
+/// Anything `parser` leaves of the line makes it malformed too.
+fn parse_line<'i, O>(
     input: &'i str,
-) -> IResult<&'i str, SessionDescriptionKeys, E> {
-    let (tail, p) = peek(alpha1)(input)?;
-    let key = match p {
-        "v" => SessionDescriptionKeys::Version,
-        "o" => SessionDescriptionKeys::Origin,
-        "s" => SessionDescriptionKeys::SessionName,
-        _ => unimplemented!("key not implemented {}", p),
-    };
-    Ok((tail, key))
+    line: &'i str,
+    parser: impl Parser<&'i str, O, Error<&'i str>>,
+) -> Result<O, SdpError> {
+    all_consuming(parser)(line)
+        .map(|(_, value)| value)
+        .map_err(|e| SdpError::malformed(input, line, e))
+}
+
+/// Parses `line`, a line of `input`, on its own, i.e. without the checks that depend on the
+/// lines around it such as their order.
+pub(crate) fn check_line(input: &str, line: &str) -> Result<(), SdpError> {
+    let (line_type, _) = split_line(line, offset_in(input, line).unwrap_or_default())?;
+    match SessionDescriptionKeys::from(line_type) {
+        SessionDescriptionKeys::Version => parse_line(input, line, parse_version).map(drop),
+        SessionDescriptionKeys::Origin => parse_line(input, line, parse_origin).map(drop),
+        SessionDescriptionKeys::SessionName => {
+            parse_line(input, line, session_name::parse_session_name).map(drop)
+        }
+        SessionDescriptionKeys::SessionInformation => {
+            parse_line(input, line, parse_information).map(drop)
+        }
+        SessionDescriptionKeys::Uri => parse_line(input, line, parse_uri).map(drop),
+        SessionDescriptionKeys::EmailAddress => parse_line(input, line, parse_email).map(drop),
+        SessionDescriptionKeys::PhoneNumber => parse_line(input, line, parse_phone).map(drop),
+        SessionDescriptionKeys::ConnectionInformation => {
+            parse_line(input, line, parse_connection).map(drop)
+        }
+        SessionDescriptionKeys::BandwidthInformation => {
+            parse_line(input, line, parse_bandwidth).map(drop)
+        }
+        SessionDescriptionKeys::TimeDescription => parse_line(input, line, parse_time).map(drop),
+        SessionDescriptionKeys::RepeatTimes => {
+            parse_line(input, line, parse_repeat_times).map(drop)
+        }
+        SessionDescriptionKeys::TimeZone => parse_line(input, line, parse_time_zones).map(drop),
+        SessionDescriptionKeys::MediaDescription => parse_line(input, line, parse_media).map(drop),
+        SessionDescriptionKeys::EncryptionKey => {
+            parse_line(input, line, parse_encryption_key).map(drop)
+        }
+        SessionDescriptionKeys::Attribute => parse_line(input, line, parse_attribute).map(drop),
+        SessionDescriptionKeys::Unknown => parse_line(input, line, parse_unknown_line).map(drop),
+    }
+}
+
+impl From<char> for SessionDescriptionKeys {
+    fn from(line_type: char) -> Self {
+        match line_type {
+            'v' => SessionDescriptionKeys::Version,
+            'o' => SessionDescriptionKeys::Origin,
+            's' => SessionDescriptionKeys::SessionName,
+            'i' => SessionDescriptionKeys::SessionInformation,
+            'u' => SessionDescriptionKeys::Uri,
+            'e' => SessionDescriptionKeys::EmailAddress,
+            'p' => SessionDescriptionKeys::PhoneNumber,
+            'c' => SessionDescriptionKeys::ConnectionInformation,
+            'b' => SessionDescriptionKeys::BandwidthInformation,
+            't' => SessionDescriptionKeys::TimeDescription,
+            'r' => SessionDescriptionKeys::RepeatTimes,
+            'z' => SessionDescriptionKeys::TimeZone,
+            'm' => SessionDescriptionKeys::MediaDescription,
+            'k' => SessionDescriptionKeys::EncryptionKey,
+            'a' => SessionDescriptionKeys::Attribute,
+            _ => SessionDescriptionKeys::Unknown,
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::net::{IpAddr, Ipv4Addr};
 
     use super::*;
+    use crate::attribute::RtpMap;
+    use crate::media_description::MediaProto;
+
+    #[test]
+    fn test_session_description() {
+        let input =
+            "v=0\r\no=jdoe 2890844526 2890842807 IN IP4 192.168.10.1\r\ns=SDP Seminar\r\nt=0 0\r\n";
+        let expected = SessionDescription::new(
+            Version::new(0),
+            Origin::new(
+                "jdoe",
+                "2890844526",
+                2890842807,
+                NetType::IN,
+                AddrType::IP4,
+                ConnectionAddress::Ip(IpAddr::V4(Ipv4Addr::new(192, 168, 10, 1))),
+            ),
+            SessionName::new("SDP Seminar"),
+            TimeDescription::new(0, 0),
+        );
+        let result = SessionDescription::from_str(input).unwrap();
+        assert_eq!(result.version, expected.version);
+        assert_eq!(result.origin, expected.origin);
+        assert_eq!(result.session_name, expected.session_name);
+        assert_eq!(result.time_descriptions, expected.time_descriptions);
+    }
+
+    #[test]
+    fn test_session_description_malformed_key() {
+        let input = "v=0\r\n9=oops\r\n";
+        let result = SessionDescription::from_str(input);
+        assert!(matches!(
+            result.unwrap_err(),
+            SdpError::MalformedLine { line_type: '9', .. }
+        ));
+    }
+
+    #[test]
+    fn test_session_description_getters() {
+        let session = SessionDescription::new(
+            Version::new(0),
+            Origin::new(
+                "jdoe",
+                "2890844526",
+                2890842807,
+                NetType::IN,
+                AddrType::IP4,
+                ConnectionAddress::Ip(IpAddr::V4(Ipv4Addr::new(192, 168, 10, 1))),
+            ),
+            SessionName::new("SDP Seminar"),
+            TimeDescription::new(0, 0),
+        );
+        assert_eq!(session.version().version(), 0);
+        assert_eq!(session.origin().username(), "jdoe");
+        assert_eq!(session.origin().session_version(), 2890842807);
+        assert_eq!(session.session_name().name(), "SDP Seminar");
+    }
+
+    #[test]
+    fn test_session_description_truncated_origin() {
+        let input = "v=0\r\no=jdoe 2890844526";
+        let result = SessionDescription::from_str(input);
+        assert!(matches!(
+            result.unwrap_err(),
+            SdpError::MalformedLine { line_type: 'o', .. }
+        ));
+    }
+
+    #[test]
+    fn test_session_description_malformed_line_position() {
+        let input = "v=0\r\no=- 1 1 IN IP4 127.0.0.1\r\ns=-\r\nt=0 0\r\nm=audio 9 RTP/AVP 0\r\na=rtpmap:x PCMU/8000\r\n";
+        match SessionDescription::from_str(input).unwrap_err() {
+            SdpError::MalformedLine {
+                line_type,
+                offset,
+                line,
+                rfc_reference,
+                ..
+            } => {
+                assert_eq!(line_type, 'a');
+                assert_eq!(&input[offset..offset + 1], "x");
+                assert_eq!(line, "a=rtpmap:x PCMU/8000");
+                assert_eq!(rfc_reference, "RFC 8866 §5.13");
+            }
+            err => panic!("unexpected error {:?}", err),
+        }
+    }
+
+    #[test]
+    fn test_session_description_unsupported_version() {
+        let input = "v=9\r\no=jdoe 2890844526 2890842807 IN IP4 192.168.10.1\r\ns=-\r\nt=0 0\r\n";
+        let result = SessionDescription::from_str(input);
+        assert_eq!(result.unwrap_err(), SdpError::UnsupportedVersion(9));
+    }
+
+    #[test]
+    fn test_session_description_corrupt_version() {
+        let input = "v=x\r\no=jdoe 2890844526 2890842807 IN IP4 192.168.10.1\r\n";
+        let result = SessionDescription::from_str(input);
+        assert_eq!(
+            result.unwrap_err(),
+            SdpError::MalformedLine {
+                line_type: 'v',
+                offset: 2,
+                line: "v=x".to_string(),
+                detail: "Digit failed at `x`".to_string(),
+                rfc_reference: "RFC 8866 §5.1",
+            }
+        );
+    }
+
+    #[test]
+    fn test_session_description_with_connection() {
+        let input = "v=0\r\no=jdoe 2890844526 2890842807 IN IP4 192.168.10.1\r\ns=SDP Seminar\r\nc=IN IP4 224.2.17.12/127\r\nt=0 0\r\n";
+        let result = SessionDescription::from_str(input).unwrap();
+        let connection = result.connection().unwrap();
+        assert_eq!(
+            connection.address(),
+            &ConnectionAddress::Ip(IpAddr::V4(Ipv4Addr::new(224, 2, 17, 12)))
+        );
+        assert_eq!(connection.ttl(), Some(127));
+    }
+
+    #[test]
+    fn test_session_description_with_media() {
+        let input = "v=0\r\no=jdoe 2890844526 2890842807 IN IP4 192.168.10.1\r\ns=SDP Seminar\r\nc=IN IP4 192.168.10.1\r\nt=0 0\r\nm=audio 49170 RTP/AVP 0\r\nm=video 51372 RTP/AVP 99\r\nc=IN IP6 ::1\r\n";
+        let result = SessionDescription::from_str(input).unwrap();
+        assert_eq!(result.media().len(), 2);
+        assert_eq!(result.media()[0].media(), "audio");
+        assert_eq!(result.media()[0].connection(), None);
+        assert_eq!(result.media()[1].media(), "video");
+        assert_eq!(result.media()[1].formats(), &["99"]);
+        assert_eq!(
+            result.media()[1].connection().unwrap().addrtype(),
+            &AddrType::IP6
+        );
+        assert_eq!(
+            result.connection().unwrap().address(),
+            &ConnectionAddress::Ip(IpAddr::V4(Ipv4Addr::new(192, 168, 10, 1)))
+        );
+    }
+
+    #[test]
+    fn test_session_description_lf_line_endings() {
+        let input = "v=0\no=jdoe 2890844526 2890842807 IN IP4 192.168.10.1\ns=SDP Seminar\nc=IN IP4 224.2.17.12/127\nt=0 0\na=recvonly\nm=audio 49170 RTP/AVP 0\nm=video 51372 RTP/AVP 99\na=rtpmap:99 h263-1998/90000";
+        let result = SessionDescription::from_str(input).unwrap();
+        assert_eq!(result.session_name().name(), "SDP Seminar");
+        assert_eq!(result.media().len(), 2);
+        assert_eq!(result.media()[1].rtpmaps().count(), 1);
+        assert_eq!(result.to_string(), input.replace('\n', "\r\n") + "\r\n");
+
+        let input = "v=0\no=- 1 1 IN IP4 127.0.0.1";
+        let result = SessionDescription::from_str(input);
+        assert_eq!(result.unwrap_err(), SdpError::MissingRequiredLine("s="));
+    }
+
+    #[test]
+    fn test_session_description_without_final_line_ending() {
+        let session = "v=0\r\no=- 1 1 IN IP4 127.0.0.1\r\ns=-\r\ni=A Seminar\r\nu=http://www.example.com/\r\ne=j.doe@example.com\r\np=+1 617 555-6011\r\nc=IN IP4 224.2.17.12/127\r\nb=CT:128\r\n";
+        for last_line in [
+            "t=0 0",
+            "t=0 0\r\nr=604800 3600 0 90000",
+            "t=0 0\r\nk=prompt",
+            "t=0 0\r\na=recvonly",
+            "t=0 0\r\na=tool:sdp-parser",
+            "t=0 0\r\nx=vendor value",
+            "t=0 0\r\nm=audio 49170 RTP/AVP 0",
+            "t=0 0\r\nm=audio 49170 RTP/AVP 0\r\ni=Audio",
+            "t=0 0\r\nm=audio 49170 RTP/AVP 0\r\nc=IN IP6 ::1",
+            "t=0 0\r\nm=audio 49170 RTP/AVP 0\r\nb=AS:64",
+            "t=0 0\r\nm=audio 49170 RTP/AVP 0\r\nk=prompt",
+            "t=0 0\r\nm=audio 49170 RTP/AVP 0\r\na=rtpmap:0 PCMU/8000",
+            "t=0 0\r\nm=audio 49170 RTP/AVP 0\r\na=ptime:20",
+        ] {
+            let input = format!("{}{}", session, last_line);
+            let terminated = format!("{}\r\n", input);
+            let expected = SessionDescription::from_str(&terminated).unwrap();
+            assert_eq!(
+                SessionDescription::from_str(&input),
+                Ok(expected),
+                "{}",
+                last_line
+            );
+        }
+    }
+
+    #[test]
+    fn test_session_description_origin_at_end_of_input() {
+        let input = "v=0\r\ns=-\r\nt=0 0\r\no=- 1 1 IN IP4 127.0.0.1";
+        let options = ParseOptions {
+            allow_out_of_order: true,
+            ..ParseOptions::default()
+        };
+        let result = SessionDescription::from_str_with_options(input, &options).unwrap();
+        assert_eq!(result.origin().username(), "-");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_session_description_serde_round_trip() {
+        let input = "v=0\r\no=jdoe 2890844526 2890842807 IN IP4 192.168.10.1\r\ns=SDP Seminar\r\nc=IN IP6 ::1\r\nt=0 0\r\na=recvonly\r\nm=audio 49170 RTP/AVP 96\r\na=rtpmap:96 opus/48000/2\r\n";
+        let result = SessionDescription::from_str(input).unwrap();
+        let json = serde_json::to_string(&result).unwrap();
+        assert!(json.contains(r#""nettype":"IN","addrtype":"IP4""#));
+        assert!(json.contains(r#""addrtype":"IP6""#));
+        let decoded: SessionDescription = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded, result);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_session_description_serde_without_time() {
+        let input = "v=0\r\no=- 1 1 IN IP4 127.0.0.1\r\ns=-\r\nt=0 0\r\n";
+        let result = SessionDescription::from_str(input).unwrap();
+        let mut value = serde_json::to_value(&result).unwrap();
+        value["time_descriptions"] = serde_json::Value::Array(Vec::new());
+        let json = value.to_string();
+        let decoded: SessionDescription = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded.time_description(), None);
+        assert_eq!(
+            decoded.validate(),
+            Err(vec![SdpError::MissingRequiredLine("t=")])
+        );
+    }
+
+    #[test]
+    fn test_session_description_borrows_input() {
+        let input = "v=0\r\no=jdoe 2890844526 2890842807 IN IP4 host.example.com\r\ns=SDP Seminar\r\ni=A Seminar\r\nu=http://www.example.com/seminars/sdp.pdf\r\ne=j.doe@example.com (Jane Doe)\r\nb=CT:512\r\nt=0 0\r\na=tool:foo\r\nm=audio 49170 RTP/AVP 96\r\na=rtpmap:96 opus/48000/2\r\n";
+        let range = input.as_bytes().as_ptr_range();
+        let borrowed = |s: &str| range.contains(&s.as_ptr());
+
+        let result = SessionDescription::from_str(input).unwrap();
+        assert!(borrowed(result.origin().username()));
+        assert!(borrowed(result.origin().session_id()));
+        assert!(borrowed(result.session_name().name()));
+        assert!(borrowed(result.information().unwrap().information()));
+        assert!(borrowed(result.uri().unwrap().uri()));
+        assert!(borrowed(result.emails()[0].address()));
+        assert!(borrowed(result.bandwidths()[0].bwtype()));
+        assert!(borrowed(result.attributes()[0].value().unwrap()));
+        let media = &result.media()[0];
+        assert!(borrowed(media.media()));
+        assert_eq!(media.media_proto(), MediaProto::RtpAvp);
+        assert!(borrowed(media.formats()[0]));
+        assert!(borrowed(media.rtpmaps().next().unwrap().encoding_name()));
+        match result.origin().unicast_address() {
+            ConnectionAddress::Fqdn(name) => assert!(borrowed(name)),
+            address => panic!("unexpected address {:?}", address),
+        }
+    }
+
+    #[test]
+    fn test_session_description_with_attributes() {
+        let input = "v=0\r\no=jdoe 2890844526 2890842807 IN IP4 192.168.10.1\r\ns=SDP Seminar\r\nt=0 0\r\na=recvonly\r\nm=audio 49170 RTP/AVP 96\r\na=rtpmap:96 opus/48000/2\r\n";
+        let result = SessionDescription::from_str(input).unwrap();
+        assert_eq!(
+            result.attributes(),
+            &[Attribute::Direction(Direction::RecvOnly)]
+        );
+        assert_eq!(
+            result.media()[0].attributes(),
+            &[Attribute::RtpMap(RtpMap::new(96, "opus", 48000, Some(2)))]
+        );
+        assert_eq!(result.media()[0].rtpmaps().count(), 1);
+    }
+
+    #[test]
+    fn test_session_description_display_round_trip() {
+        let input = "v=0\r\no=jdoe 2890844526 2890842807 IN IP4 192.168.10.1\r\ns=SDP Seminar\r\nc=IN IP4 224.2.17.12/127\r\nt=0 0\r\na=recvonly\r\nm=audio 49170 RTP/AVP 0\r\nm=video 51372 RTP/AVP 99\r\na=rtpmap:99 h263-1998/90000\r\n";
+        let result = SessionDescription::from_str(input).unwrap();
+        let output = result.to_string();
+        assert_eq!(output, input);
+        assert_eq!(SessionDescription::from_str(&output).unwrap(), result);
+    }
+
+    #[test]
+    fn test_session_description_with_bandwidth() {
+        let input = "v=0\r\no=jdoe 2890844526 2890842807 IN IP4 192.168.10.1\r\ns=SDP Seminar\r\nb=CT:512\r\nt=0 0\r\nm=audio 49170 RTP/AVP 0\r\nb=AS:128\r\nb=X-YZ:64\r\n";
+        let result = SessionDescription::from_str(input).unwrap();
+        assert_eq!(result.bandwidths(), &[Bandwidth::new("CT", 512)]);
+        assert_eq!(
+            result.media()[0].bandwidths(),
+            &[Bandwidth::new("AS", 128), Bandwidth::new("X-YZ", 64)]
+        );
+        assert_eq!(result.to_string(), input);
+    }
+
+    #[test]
+    fn test_session_description_bandwidth_scopes() {
+        let input = "v=0\r\no=- 1 1 IN IP4 127.0.0.1\r\ns=-\r\nb=AS:256\r\nt=0 0\r\nm=audio 49170 RTP/AVP 0\r\nb=AS:128\r\nm=video 51372 RTP/AVP 99\r\n";
+        let result = SessionDescription::from_str(input).unwrap();
+        assert_eq!(result.bandwidths(), &[Bandwidth::new("AS", 256)]);
+        assert_eq!(result.media()[0].bandwidths(), &[Bandwidth::new("AS", 128)]);
+        assert!(result.media()[1].bandwidths().is_empty());
+    }
+
+    #[test]
+    fn test_session_description_with_information() {
+        let input = "v=0\r\no=jdoe 2890844526 2890842807 IN IP4 192.168.10.1\r\ns=SDP Seminar\r\ni=A Seminar on the session description protocol\r\nt=0 0\r\nm=audio 49170 RTP/AVP 0\r\ni=Main audio\r\n";
+        let result = SessionDescription::from_str(input).unwrap();
+        assert_eq!(
+            result.information().unwrap().information(),
+            "A Seminar on the session description protocol"
+        );
+        assert_eq!(
+            result.media()[0].information(),
+            Some(&SessionInformation::new("Main audio"))
+        );
+        assert_eq!(result.to_string(), input);
+    }
 
-    // FIXME: Tjere are issues with this test
-    // #[test]
-    // fn test_session_description() {
-    //     let input = "v=0\r\no=jdoe 2890844526 2890842807 IN IP4 192.168.10.1\r\ns=SDP Seminar\r\n";
-    //     let expected = SessionDescription::new(
-    //         Version::new(0),
-    //         Origin::new(
-    //             "jdoe",
-    //             "2890844526",
-    //             2890842807,
-    //             NetType::IN,
-    //             AddrType::IP4,
-    //             IpAddr::V4(Ipv4Addr::new(192, 168, 10, 1)),
-    //         ),
-    //         SessionName::new("SDP Seminar"),
-    //     );
-    //     let result = SessionDescription::from_str(input).unwrap();
-    //     assert_eq!(result.version, expected.version);
-    //     assert_eq!(result.origin, expected.origin);
-    //     assert_eq!(result.session_name, expected.session_name);
-    // }
-
-    #[test]
-    fn test_peek_key() {
-        let (tail, key) = peek_key::<()>("v=0\r\n").unwrap();
-        assert_eq!(tail, "v=0\r\n");
-        match key {
-            SessionDescriptionKeys::Version => {}
-            _ => panic!("unexpected key"),
+    #[test]
+    fn test_session_description_with_uri() {
+        let input = "v=0\r\no=jdoe 2890844526 2890842807 IN IP4 192.168.10.1\r\ns=SDP Seminar\r\nu=http://www.example.com/seminars/sdp.pdf\r\nt=0 0\r\n";
+        let result = SessionDescription::from_str(input).unwrap();
+        assert_eq!(
+            result.uri(),
+            Some(&Uri::new("http://www.example.com/seminars/sdp.pdf"))
+        );
+        assert_eq!(result.to_string(), input);
+    }
+
+    #[test]
+    fn test_session_description_with_contacts() {
+        let input = "v=0\r\no=jdoe 2890844526 2890842807 IN IP4 192.168.10.1\r\ns=SDP Seminar\r\ne=j.doe@example.com (Jane Doe)\r\ne=Jane Doe <jane@example.org>\r\np=+1 617 555-6011\r\nt=0 0\r\n";
+        let result = SessionDescription::from_str(input).unwrap();
+        assert_eq!(result.emails().len(), 2);
+        assert_eq!(result.emails()[1].address(), "jane@example.org");
+        assert_eq!(result.phones(), &[Phone::new("+1 617 555-6011")]);
+        assert_eq!(result.to_string(), input);
+    }
+
+    #[test]
+    fn test_session_description_with_repeat_times() {
+        let input = "v=0\r\no=jdoe 2890844526 2890842807 IN IP4 192.168.10.1\r\ns=SDP Seminar\r\nt=3034423619 3042462419\r\nr=7d 1h 0 25h\r\n";
+        let result = SessionDescription::from_str(input).unwrap();
+        let repeat_times = result.time_description().unwrap().repeat_times();
+        assert_eq!(repeat_times.len(), 1);
+        assert_eq!(repeat_times[0].offsets(), &[0, 90000]);
+    }
+
+    #[test]
+    fn test_session_description_multiple_time_descriptions() {
+        let input = "v=0\r\no=jdoe 2890844526 2890842807 IN IP4 192.168.10.1\r\ns=SDP Seminar\r\nt=3034423619 3042462419\r\nr=7d 1h 0 25h\r\nt=3042462419 3050501219\r\nm=audio 49170 RTP/AVP 0\r\n";
+        let result = SessionDescription::from_str(input).unwrap();
+        let time_descriptions = result.time_descriptions();
+        assert_eq!(time_descriptions.len(), 2);
+        assert_eq!(time_descriptions[0].repeat_times().len(), 1);
+        assert_eq!(
+            time_descriptions[1],
+            TimeDescription::new(3042462419, 3050501219)
+        );
+        assert_eq!(result.time_description(), time_descriptions.first());
+        assert_eq!(
+            result.to_string(),
+            input.replace("r=7d 1h 0 25h", "r=604800 3600 0 90000")
+        );
+
+        let input = "v=0\r\no=- 1 1 IN IP4 127.0.0.1\r\ns=-\r\nt=3034423619 3042462419\r\nt=3042462419 3050501219\r\n";
+        let result = SessionDescription::from_str(input).unwrap();
+        assert_eq!(result.time_descriptions().len(), 2);
+        assert_eq!(result.to_string(), input);
+    }
+
+    #[test]
+    fn test_session_description_repeat_times_without_time() {
+        let input = "v=0\r\no=jdoe 2890844526 2890842807 IN IP4 192.168.10.1\r\ns=SDP Seminar\r\nr=7d 1h 0 25h\r\n";
+        let result = SessionDescription::from_str(input);
+        assert_eq!(result.unwrap_err(), SdpError::MissingRequiredLine("t="));
+    }
+
+    #[test]
+    fn test_session_description_discards_encryption_key() {
+        let input = "v=0\r\no=jdoe 2890844526 2890842807 IN IP4 192.168.10.1\r\ns=SDP Seminar\r\nt=0 0\r\nk=clear:secret\r\n";
+        let result = SessionDescription::from_str(input).unwrap();
+        assert_eq!(result.encryption_key(), None);
+    }
+
+    #[test]
+    fn test_session_description_keeps_encryption_key() {
+        let input = "v=0\r\no=jdoe 2890844526 2890842807 IN IP4 192.168.10.1\r\ns=SDP Seminar\r\nt=0 0\r\nk=clear:secret\r\n";
+        let options = ParseOptions {
+            keep_encryption_key: true,
+            ..ParseOptions::default()
+        };
+        let result = SessionDescription::from_str_with_options(input, &options).unwrap();
+        assert_eq!(
+            result.encryption_key(),
+            Some(&EncryptionKey::Clear("secret"))
+        );
+        assert_eq!(result.to_string(), input);
+    }
+
+    #[test]
+    fn test_session_description_media_encryption_key() {
+        let input = "v=0\r\no=- 1 1 IN IP4 127.0.0.1\r\ns=-\r\nt=0 0\r\nk=clear:session\r\nm=audio 9 RTP/AVP 0\r\nk=base64:bWVkaWE=\r\na=rtpmap:0 PCMU/8000\r\nm=video 9 RTP/AVP 96\r\nk=prompt\r\nm=text 9 RTP/AVP 98\r\n";
+        let options = ParseOptions {
+            keep_encryption_key: true,
+            ..ParseOptions::default()
+        };
+        let result = SessionDescription::from_str_with_options(input, &options).unwrap();
+        assert_eq!(
+            result.encryption_key(),
+            Some(&EncryptionKey::Clear("session"))
+        );
+        assert_eq!(
+            result.media()[0].encryption_key(),
+            Some(&EncryptionKey::Base64("bWVkaWE="))
+        );
+        assert_eq!(
+            result.media()[1].encryption_key(),
+            Some(&EncryptionKey::Prompt)
+        );
+        assert_eq!(result.media()[2].encryption_key(), None);
+        assert_eq!(result.to_string(), input);
+
+        let result = SessionDescription::from_str(input).unwrap();
+        assert_eq!(result.media()[0].encryption_key(), None);
+    }
+
+    #[test]
+    fn test_session_description_strict_and_lenient() {
+        let input = "v=0\r\no=- 1 1 IN IP4 127.0.0.1\r\ns=-\r\nt=0 0\r\nc=IN IP4 127.0.0.1\r\n";
+        let result = SessionDescription::from_str(input);
+        assert_eq!(
+            result.unwrap_err(),
+            SdpError::OutOfOrderLine {
+                expected: 't',
+                found: 'c'
+            }
+        );
+        let result =
+            SessionDescription::from_str_with_options(input, &ParseOptions::lenient()).unwrap();
+        assert!(result.connection().is_some());
+
+        let input = "v=0\r\no=- 1 1 IN IP4 127.0.0.1\r\ns=-\r\nm=audio 9 RTP/AVP 0\r\n";
+        let result =
+            SessionDescription::from_str_with_options(input, &ParseOptions::lenient()).unwrap();
+        assert!(result.time_description().unwrap().is_permanent());
+    }
+
+    #[test]
+    fn test_session_description_discards_unknown_lines() {
+        let input = "v=0\r\no=- 1 1 IN IP4 127.0.0.1\r\ns=-\r\nt=0 0\r\ny=yes\r\n";
+        let options = ParseOptions {
+            preserve_unknown_lines: false,
+            ..ParseOptions::default()
+        };
+        let result = SessionDescription::from_str_with_options(input, &options).unwrap();
+        assert!(result.unknown_lines().is_empty());
+    }
+
+    #[test]
+    fn test_session_description_with_fingerprint() {
+        let input = "v=0\r\no=- 1 1 IN IP4 127.0.0.1\r\ns=-\r\nt=0 0\r\na=fingerprint:sha-256 4A:AD:B9:B1\r\nm=audio 9 UDP/TLS/RTP/SAVPF 111\r\na=fingerprint:sha-1 3F:82\r\n";
+        let result = SessionDescription::from_str(input).unwrap();
+        assert_eq!(result.fingerprint().unwrap().hash_function(), "sha-256");
+        assert_eq!(
+            result.media()[0].fingerprint(),
+            Some(&Fingerprint::new("sha-1", "3F:82"))
+        );
+    }
+
+    #[test]
+    fn test_session_description_malformed_fingerprint() {
+        let input =
+            "v=0\r\no=- 1 1 IN IP4 127.0.0.1\r\ns=-\r\nt=0 0\r\na=fingerprint:sha-256 nothex\r\n";
+        let result = SessionDescription::from_str(input);
+        assert!(matches!(
+            result.unwrap_err(),
+            SdpError::MalformedLine { line_type: 'a', .. }
+        ));
+    }
+
+    #[test]
+    fn test_session_description_with_bundle_group() {
+        let input = "v=0\r\no=- 1 1 IN IP4 127.0.0.1\r\ns=-\r\nt=0 0\r\na=group:BUNDLE audio video\r\nm=audio 9 UDP/TLS/RTP/SAVPF 111\r\na=mid:audio\r\nm=video 9 UDP/TLS/RTP/SAVPF 96\r\na=mid:video\r\n";
+        let result = SessionDescription::from_str(input).unwrap();
+        let groups: Vec<_> = result.groups().collect();
+        assert_eq!(groups, vec![&Group::new("BUNDLE", vec!["audio", "video"])]);
+        assert_eq!(result.media()[1].mid(), Some("video"));
+        assert_eq!(result.to_string(), input);
+    }
+
+    #[test]
+    fn test_session_description_group_unknown_mid() {
+        let input = "v=0\r\no=- 1 1 IN IP4 127.0.0.1\r\ns=-\r\nt=0 0\r\na=group:BUNDLE audio video\r\nm=audio 9 UDP/TLS/RTP/SAVPF 111\r\na=mid:audio\r\n";
+        let result = SessionDescription::from_str(input);
+        assert_eq!(
+            result.unwrap_err(),
+            SdpError::UnknownMediaId("video".to_string())
+        );
+    }
+
+    #[test]
+    fn test_session_description_validate() {
+        let input = "v=0\r\no=- 1 1 IN IP4 127.0.0.1\r\ns=-\r\nt=0 0\r\nm=audio 49170 RTP/AVP 0\r\nc=IN IP4 224.2.17.12/127\r\n";
+        assert_eq!(
+            SessionDescription::from_str(input).unwrap().validate(),
+            Ok(())
+        );
+
+        let input = "v=0\r\no=- 1 1 IN IP4 127.0.0.1\r\ns=-\r\nc=IN IP4 224.2.17.12/127\r\nt=0 0\r\nm=audio 49170 RTP/AVP 0\r\n";
+        assert_eq!(
+            SessionDescription::from_str(input).unwrap().validate(),
+            Ok(())
+        );
+
+        let input = "v=0\r\no=- 1 1 IN IP6 ::ffff:192.168.1.1\r\ns=-\r\nc=IN IP6 ::ffff:192.168.1.1\r\nt=0 0\r\n";
+        assert_eq!(
+            SessionDescription::from_str(input).unwrap().validate(),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_session_description_validate_duplicate_lines() {
+        let input = "v=0\r\no=- 1 1 IN IP4 127.0.0.1\r\ns=-\r\nc=IN IP4 127.0.0.1\r\no=- 2 2 IN IP4 127.0.0.2\r\ns=Second\r\nt=0 0\r\n";
+        assert_eq!(
+            SessionDescription::from_str(input).unwrap_err(),
+            SdpError::OutOfOrderLine {
+                expected: 'b',
+                found: 'o'
+            }
+        );
+        let session =
+            SessionDescription::from_str_with_options(input, &ParseOptions::lenient()).unwrap();
+        assert_eq!(session.origin().session_id(), "1");
+        assert_eq!(session.session_name().name(), "-");
+        assert_eq!(
+            session.validate(),
+            Err(vec![
+                SdpError::DuplicateLine('o'),
+                SdpError::DuplicateLine('s')
+            ])
+        );
+
+        let input = "v=0\r\no=- 1 1 IN IP4 127.0.0.1\r\ns=-\r\ni=First\r\nu=http://a.example.com\r\nc=IN IP4 10.0.0.1\r\ni=Second\r\nu=http://b.example.com\r\nc=IN IP4 10.0.0.2\r\nt=0 0\r\nm=audio 9 RTP/AVP 0\r\ni=Audio\r\ni=Other\r\n";
+        assert!(SessionDescription::from_str(input).is_err());
+        let session =
+            SessionDescription::from_str_with_options(input, &ParseOptions::lenient()).unwrap();
+        assert_eq!(session.information().unwrap().information(), "First");
+        assert_eq!(session.uri().unwrap().uri(), "http://a.example.com");
+        assert_eq!(
+            session.connection().unwrap().address(),
+            &ConnectionAddress::Ip(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)))
+        );
+        assert_eq!(
+            session.media()[0].information().unwrap().information(),
+            "Audio"
+        );
+        assert_eq!(
+            session.validate(),
+            Err(vec![
+                SdpError::DuplicateLine('i'),
+                SdpError::DuplicateLine('u'),
+                SdpError::DuplicateLine('c'),
+                SdpError::DuplicateLine('i')
+            ])
+        );
+    }
+
+    #[test]
+    fn test_session_description_validate_extmap_ids() {
+        let input = "v=0\r\no=- 1 1 IN IP4 127.0.0.1\r\ns=-\r\nc=IN IP4 127.0.0.1\r\nt=0 0\r\nm=audio 9 RTP/AVP 0\r\na=extmap:1 urn:ietf:params:rtp-hdrext:ssrc-audio-level\r\na=extmap:3 urn:ietf:params:rtp-hdrext:sdes:mid\r\na=extmap:1/sendonly urn:ietf:params:rtp-hdrext:toffset\r\nm=video 9 RTP/AVP 96\r\na=extmap:1 urn:ietf:params:rtp-hdrext:toffset\r\na=extmap:3 urn:ietf:params:rtp-hdrext:sdes:mid\r\n";
+        let session = SessionDescription::from_str(input).unwrap();
+        assert_eq!(
+            session.validate(),
+            Err(vec![SdpError::DuplicateExtmapId(1)])
+        );
+    }
+
+    #[test]
+    fn test_session_description_validate_collects_errors() {
+        let input = "v=0\r\no=- 1 1 IN IP4 127.0.0.1\r\ns=-\r\nt=0 0\r\nm=audio 49170 RTP/AVP 0\r\nc=IN IP4 224.2.17.12/127\r\nm=video 51372 RTP/AVP 99\r\n";
+        let mut session = SessionDescription::from_str(input).unwrap();
+        session.version = Version::new(1);
+        session.session_name = SessionName::new("");
+        session.time_descriptions.clear();
+        assert_eq!(
+            session.validate(),
+            Err(vec![
+                SdpError::UnsupportedVersion(1),
+                SdpError::EmptySessionName,
+                SdpError::MissingRequiredLine("t="),
+                SdpError::MissingRequiredLine("c="),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_session_description_msid() {
+        let input = "v=0\r\no=- 1 1 IN IP4 127.0.0.1\r\ns=-\r\nt=0 0\r\na=msid-semantic: WMS stream\r\nm=audio 9 RTP/AVP 0\r\na=msid:stream audio-track\r\nm=video 9 RTP/AVP 96\r\na=msid:stream\r\n";
+        let result = SessionDescription::from_str(input).unwrap();
+        let semantic = result.msid_semantics().next().unwrap();
+        assert_eq!(semantic.semantics(), "WMS");
+        assert_eq!(semantic.stream_ids(), &["stream"]);
+        let msids: Vec<_> = result
+            .media()
+            .iter()
+            .flat_map(|m| m.msids())
+            .map(|msid| (msid.stream_id(), msid.track_id()))
+            .collect();
+        assert_eq!(msids, [("stream", Some("audio-track")), ("stream", None)]);
+        assert_eq!(result.to_string(), input);
+    }
+
+    #[test]
+    fn test_session_description_media_by_type() {
+        let input = "v=0\r\no=- 1 1 IN IP4 127.0.0.1\r\ns=-\r\nt=0 0\r\nm=audio 49170 RTP/AVP 0\r\nm=video 51372 RTP/AVP 99\r\nm=video 51374 RTP/AVP 100\r\n";
+        let result = SessionDescription::from_str(input).unwrap();
+        let video: Vec<_> = result.media_by_type("video").map(|m| m.port()).collect();
+        assert_eq!(video, [51372, 51374]);
+        assert_eq!(result.media_by_type("audio").count(), 1);
+        assert!(result.media_by_type("application").next().is_none());
+    }
+
+    #[test]
+    fn test_session_description_ice_options() {
+        let input = "v=0\r\no=- 1 1 IN IP4 127.0.0.1\r\ns=-\r\nt=0 0\r\na=ice-options:trickle renomination\r\n";
+        let result = SessionDescription::from_str(input).unwrap();
+        assert_eq!(result.ice_options(), &["trickle", "renomination"]);
+        assert!(result.ice_options().contains(&"trickle"));
+
+        let input = "v=0\r\no=- 1 1 IN IP4 127.0.0.1\r\ns=-\r\nt=0 0\r\n";
+        let result = SessionDescription::from_str(input).unwrap();
+        assert!(result.ice_options().is_empty());
+    }
+
+    #[test]
+    fn test_session_description_preserve_attribute_lines() {
+        let input = "v=0\r\no=- 1 1 IN IP4 127.0.0.1\r\ns=-\r\nt=0 0\r\na=msid-semantic:WMS *\r\nm=audio 9 RTP/AVP 0\r\na=ptime:020\r\na=rtpmap:0 PCMU/8000\r\n";
+        let options = ParseOptions {
+            preserve_attribute_lines: true,
+            ..ParseOptions::default()
+        };
+        let mut result = SessionDescription::from_str_with_options(input, &options).unwrap();
+        assert_eq!(result.to_string(), input);
+        assert_eq!(
+            result.attributes_mut().lines().next().unwrap().1,
+            Some("a=msid-semantic:WMS *")
+        );
+
+        result.media[0]
+            .attributes_mut()
+            .replace(0, Attribute::Ptime(30));
+        assert_eq!(
+            result.to_string(),
+            input.replace("a=ptime:020", "a=ptime:30")
+        );
+
+        let result = SessionDescription::from_str(input).unwrap();
+        assert_ne!(result.to_string(), input);
+    }
+
+    #[test]
+    fn test_session_description_preserve_lines() {
+        let input = "v=0\no=- 1 1 IN IP4 127.0.0.1\ns=-\nc=IN IP4 224.2.17.12/127\nt=2873397496 2873404696\nr=7d 1h 0 25h\nz=2882844526 -1h\na=ptime:020\nx=vendor value\nm=audio 9 RTP/AVP 0\nb=AS:64\na=rtpmap:0 PCMU/8000\na=sendrecv\n";
+        let options = ParseOptions {
+            preserve_attribute_lines: true,
+            ..ParseOptions::default()
+        };
+        let mut result = SessionDescription::from_str_with_options(input, &options).unwrap();
+        assert_eq!(result.to_string(), input);
+
+        let index = result.media()[0].attributes().len() - 1;
+        result.media_mut()[0]
+            .attributes_mut()
+            .replace(index, Attribute::Direction(Direction::RecvOnly));
+        assert_eq!(
+            result.to_string(),
+            input.replace("a=sendrecv\n", "a=recvonly\r\n")
+        );
+
+        result.media_mut()[0].reject();
+        assert!(result
+            .to_string()
+            .ends_with("x=vendor value\nm=audio 0 RTP/AVP 0\r\n"));
+    }
+
+    #[test]
+    fn test_session_description_candidates_by_priority() {
+        let input = "v=0\r\no=- 1 1 IN IP4 127.0.0.1\r\ns=-\r\nt=0 0\r\nm=audio 9 UDP/TLS/RTP/SAVPF 111\r\na=candidate:2 1 udp 1694498815 203.0.113.7 61665 typ srflx raddr 192.168.1.5 rport 61665\r\na=candidate:3 1 udp 16777215 198.51.100.9 3478 typ relay raddr 203.0.113.7 rport 61665\r\na=candidate:1 1 udp 2130706431 192.168.1.5 61665 typ host\r\n";
+        let result = SessionDescription::from_str(input).unwrap();
+        let media = &result.media()[0];
+        let foundations: Vec<_> = media
+            .candidates_by_priority()
+            .iter()
+            .map(|candidate| candidate.foundation())
+            .collect();
+        assert_eq!(foundations, ["1", "2", "3"]);
+        let foundations: Vec<_> = media
+            .candidates()
+            .map(|candidate| candidate.foundation())
+            .collect();
+        assert_eq!(foundations, ["2", "3", "1"]);
+    }
+
+    #[test]
+    fn test_session_description_end_of_candidates() {
+        let input = "v=0\r\no=- 1 1 IN IP4 127.0.0.1\r\ns=-\r\nt=0 0\r\nm=audio 9 UDP/TLS/RTP/SAVPF 111\r\na=candidate:1 1 UDP 2130706431 10.0.0.1 9 typ host\r\na=end-of-candidates\r\nm=video 9 UDP/TLS/RTP/SAVPF 96\r\n";
+        let result = SessionDescription::from_str(input).unwrap();
+        assert!(!result.end_of_candidates());
+        assert!(result.media()[0].end_of_candidates());
+        assert!(!result.media()[1].end_of_candidates());
+
+        let input = "v=0\r\no=- 1 1 IN IP4 127.0.0.1\r\ns=-\r\nt=0 0\r\na=end-of-candidates\r\nm=audio 9 UDP/TLS/RTP/SAVPF 111\r\n";
+        let result = SessionDescription::from_str(input).unwrap();
+        assert!(result.end_of_candidates());
+        assert!(!result.media()[0].end_of_candidates());
+    }
+
+    #[test]
+    fn test_session_description_rtcp_mux() {
+        let input = "v=0\r\no=- 1 1 IN IP4 127.0.0.1\r\ns=-\r\nt=0 0\r\na=rtcp-mux\r\nm=audio 9 RTP/AVP 0\r\na=rtcp-mux\r\nm=video 9 RTP/AVP 96\r\na=rtcp-mux\r\na=rtcp-mux-only\r\nm=audio 9 RTP/AVP 8\r\na=rtcp-mux:yes\r\n";
+        let result = SessionDescription::from_str(input).unwrap();
+        let media = result.media();
+        assert!(media[0].rtcp_mux());
+        assert!(!media[0].rtcp_mux_only());
+        assert!(media[1].rtcp_mux());
+        assert!(media[1].rtcp_mux_only());
+        assert!(!media[2].rtcp_mux());
+    }
+
+    #[test]
+    fn test_session_description_clock_references() {
+        let input = "v=0\r\no=- 1 1 IN IP4 192.168.1.10\r\ns=ST 2110-20\r\nt=0 0\r\nm=video 50000 RTP/AVP 96\r\nc=IN IP4 239.100.9.10/32\r\na=rtpmap:96 raw/90000\r\na=ts-refclk:ptp=IEEE1588-2008:39-A7-94-FF-FE-07-CB-D0:37\r\na=ts-refclk:localmac=40-a3-6b-a0-2b-d2\r\na=mediaclk:direct=0\r\nm=audio 50010 RTP/AVP 97\r\na=rtpmap:97 L24/48000/2\r\n";
+        let result = SessionDescription::from_str(input).unwrap();
+        let video = &result.media()[0];
+        let sources: Vec<_> = video.ts_refclks().map(|refclk| refclk.source()).collect();
+        assert_eq!(sources, ["ptp", "localmac"]);
+        let mediaclk = video.mediaclk().unwrap();
+        assert_eq!((mediaclk.source(), mediaclk.value()), ("direct", Some("0")));
+        assert_eq!(result.media()[1].ts_refclks().count(), 0);
+        assert!(result.media()[1].mediaclk().is_none());
+        assert_eq!(result.to_string(), input);
+    }
+
+    #[test]
+    fn test_session_description_reduced_size_rtcp() {
+        let input = "v=0\r\no=- 1 1 IN IP4 127.0.0.1\r\ns=-\r\nt=0 0\r\na=rtcp-rsize\r\nm=video 9 UDP/TLS/RTP/SAVPF 96\r\na=rtcp-mux\r\na=rtcp-rsize\r\nm=audio 9 UDP/TLS/RTP/SAVPF 111\r\na=rtcp-mux\r\n";
+        let result = SessionDescription::from_str(input).unwrap();
+        let media = result.media();
+        assert!(media[0].reduced_size_rtcp());
+        assert!(!media[1].reduced_size_rtcp());
+        assert_eq!(result.to_string(), input);
+    }
+
+    #[test]
+    fn test_session_description_data_channel() {
+        let input = "v=0\r\no=- 1 1 IN IP4 127.0.0.1\r\ns=-\r\nt=0 0\r\nm=application 9 UDP/DTLS/SCTP webrtc-datachannel\r\na=sctp-port:5000\r\na=max-message-size:262144\r\n";
+        let result = SessionDescription::from_str(input).unwrap();
+        assert_eq!(result.media()[0].sctp_port(), Some(5000));
+        assert_eq!(result.media()[0].max_message_size(), Some(262144));
+
+        let input = "v=0\r\no=- 1 1 IN IP4 127.0.0.1\r\ns=-\r\nt=0 0\r\nm=application 9 UDP/DTLS/SCTP webrtc-datachannel\r\na=sctp-port:any\r\n";
+        assert!(matches!(
+            SessionDescription::from_str(input).unwrap_err(),
+            SdpError::MalformedLine { line_type: 'a', .. }
+        ));
+    }
+
+    #[test]
+    fn test_session_description_ptime() {
+        let input = "v=0\r\no=- 1 1 IN IP4 127.0.0.1\r\ns=-\r\nt=0 0\r\nm=audio 9 RTP/AVP 0\r\na=ptime:20\r\na=maxptime:120\r\nm=audio 9 RTP/AVP 8\r\n";
+        let result = SessionDescription::from_str(input).unwrap();
+        assert_eq!(result.media()[0].ptime(), Some(20));
+        assert_eq!(result.media()[0].maxptime(), Some(120));
+        assert_eq!(result.media()[1].ptime(), None);
+
+        let input = "v=0\r\no=- 1 1 IN IP4 127.0.0.1\r\ns=-\r\nt=0 0\r\nm=audio 9 RTP/AVP 0\r\na=ptime:abc\r\n";
+        let result = SessionDescription::from_str(input);
+        assert!(matches!(
+            result.unwrap_err(),
+            SdpError::MalformedLine { line_type: 'a', .. }
+        ));
+    }
+
+    #[test]
+    fn test_session_description_effective_connection() {
+        let input = "v=0\r\no=- 1 1 IN IP4 127.0.0.1\r\ns=-\r\nc=IN IP4 192.168.10.1\r\nt=0 0\r\nm=audio 9 RTP/AVP 0\r\nm=video 9 RTP/AVP 96\r\nc=IN IP6 ::1\r\n";
+        let result = SessionDescription::from_str(input).unwrap();
+        let audio = result.media()[0].effective_connection(&result).unwrap();
+        assert_eq!(audio.addrtype(), &AddrType::IP4);
+        assert_eq!(
+            audio.address(),
+            &ConnectionAddress::Ip(IpAddr::V4(Ipv4Addr::new(192, 168, 10, 1)))
+        );
+        let video = result.media()[1].effective_connection(&result).unwrap();
+        assert_eq!(video.addrtype(), &AddrType::IP6);
+        assert_eq!(result.media()[0].effective_connections(&result).len(), 1);
+
+        let input = "v=0\r\no=- 1 1 IN IP4 127.0.0.1\r\ns=-\r\nt=0 0\r\nm=audio 9 RTP/AVP 0\r\n";
+        let result = SessionDescription::from_str(input).unwrap();
+        assert_eq!(result.media()[0].effective_connection(&result), None);
+        assert!(result.media()[0].effective_connections(&result).is_empty());
+    }
+
+    #[test]
+    fn test_session_description_media_connections() {
+        let input = "v=0\r\no=- 1 1 IN IP4 127.0.0.1\r\ns=-\r\nc=IN IP4 10.0.0.1\r\nt=0 0\r\nm=video 9 RTP/AVP 96\r\nc=IN IP4 224.2.1.1/127\r\nc=IN IP4 224.2.1.2/127\r\n";
+        let result = SessionDescription::from_str(input).unwrap();
+        let media = &result.media()[0];
+        let addresses: Vec<_> = media
+            .effective_connections(&result)
+            .iter()
+            .map(|connection| connection.address().to_string())
+            .collect();
+        assert_eq!(addresses, ["224.2.1.1", "224.2.1.2"]);
+        assert_eq!(media.connection(), media.connections().first());
+        assert_eq!(result.to_string(), input);
+    }
+
+    #[test]
+    fn test_session_description_effective_direction() {
+        let input = "v=0\r\no=- 1 1 IN IP4 127.0.0.1\r\ns=-\r\nt=0 0\r\na=sendonly\r\nm=audio 9 RTP/AVP 0\r\nm=video 9 RTP/AVP 96\r\na=inactive\r\n";
+        let result = SessionDescription::from_str(input).unwrap();
+        assert_eq!(result.direction(), Some(Direction::SendOnly));
+        assert_eq!(result.media()[0].direction(), None);
+        assert_eq!(
+            result.media()[0].effective_direction(&result),
+            Direction::SendOnly
+        );
+        assert_eq!(
+            result.media()[1].effective_direction(&result),
+            Direction::Inactive
+        );
+
+        let input = "v=0\r\no=- 1 1 IN IP4 127.0.0.1\r\ns=-\r\nt=0 0\r\nm=audio 9 RTP/AVP 0\r\n";
+        let result = SessionDescription::from_str(input).unwrap();
+        assert_eq!(
+            result.media()[0].effective_direction(&result),
+            Direction::SendRecv
+        );
+    }
+
+    #[test]
+    fn test_session_description_media_direction_overrides_session() {
+        let input = "v=0\r\no=- 1 1 IN IP4 127.0.0.1\r\ns=-\r\nt=0 0\r\na=inactive\r\nm=audio 9 RTP/AVP 0\r\na=sendrecv\r\nm=video 9 RTP/AVP 96\r\n";
+        let result = SessionDescription::from_str(input).unwrap();
+        assert_eq!(result.direction(), Some(Direction::Inactive));
+        assert_eq!(result.media()[0].direction(), Some(Direction::SendRecv));
+        assert_eq!(
+            result.media()[0].effective_direction(&result),
+            Direction::SendRecv
+        );
+        assert_eq!(
+            result.media()[1].effective_direction(&result),
+            Direction::Inactive
+        );
+    }
+
+    #[test]
+    fn test_session_description_with_ssrcs() {
+        let input = "v=0\r\no=- 1 1 IN IP4 127.0.0.1\r\ns=-\r\nt=0 0\r\nm=video 9 UDP/TLS/RTP/SAVPF 96 97\r\na=ssrc-group:FID 1234 5678\r\na=ssrc:1234 cname:user@host\r\na=ssrc:1234 msid:stream track\r\na=ssrc:5678 cname:user@host\r\n";
+        let result = SessionDescription::from_str(input).unwrap();
+        let media = &result.media()[0];
+        let ssrcs: Vec<_> = media
+            .ssrcs()
+            .map(|ssrc| (ssrc.id(), ssrc.attribute(), ssrc.value()))
+            .collect();
+        assert_eq!(
+            ssrcs,
+            vec![
+                (1234, "cname", Some("user@host")),
+                (1234, "msid", Some("stream track")),
+                (5678, "cname", Some("user@host")),
+            ]
+        );
+        assert_eq!(media.ssrc_groups().next().unwrap().ssrcs(), &[1234, 5678]);
+        assert_eq!(media.attributes_by_name("ssrc").count(), 3);
+        assert_eq!(media.attributes_by_name("ssrc-group").count(), 1);
+        assert_eq!(media.attributes_by_name("rtpmap").count(), 0);
+        assert_eq!(result.to_string(), input);
+    }
+
+    #[test]
+    fn test_session_description_tls_id() {
+        let offer = "v=0\r\no=- 1 1 IN IP4 127.0.0.1\r\ns=-\r\nt=0 0\r\na=tls-id:session0123456789abcdef\r\nm=audio 9 UDP/TLS/RTP/SAVPF 111\r\na=tls-id:abc3de65cddef001be82\r\nm=video 9 UDP/TLS/RTP/SAVPF 96\r\n";
+        let offer = SessionDescription::from_str(offer).unwrap();
+        assert_eq!(offer.tls_id(), Some("session0123456789abcdef"));
+        assert_eq!(offer.media()[0].tls_id(), Some("abc3de65cddef001be82"));
+        assert_eq!(offer.media()[1].tls_id(), None);
+        assert_eq!(
+            offer.media()[1].effective_tls_id(&offer),
+            Some("session0123456789abcdef")
+        );
+
+        let answer = "v=0\r\no=- 1 2 IN IP4 127.0.0.1\r\ns=-\r\nt=0 0\r\nm=audio 9 UDP/TLS/RTP/SAVPF 111\r\na=dtls-id:dcb3ed56ddcfe100eb28\r\nm=video 9 UDP/TLS/RTP/SAVPF 96\r\n";
+        let answer = SessionDescription::from_str(answer).unwrap();
+        assert_eq!(answer.media()[0].tls_id(), Some("dcb3ed56ddcfe100eb28"));
+        assert!(answer.media()[0].tls_id_changed(&offer.media()[0]));
+        assert!(!offer.media()[0].tls_id_changed(&offer.media()[0]));
+        assert!(!answer.media()[1].tls_id_changed(&offer.media()[1]));
+    }
+
+    #[test]
+    fn test_session_description_try_from() {
+        let input = "v=0\r\no=- 1 1 IN IP4 127.0.0.1\r\ns=-\r\nt=0 0\r\n";
+        let result: SessionDescription = input.try_into().unwrap();
+        assert_eq!(result, SessionDescription::from_str(input).unwrap());
+
+        let result = SessionDescription::try_from("v=0\r\ns=-\r\n");
+        assert_eq!(result.unwrap_err(), SdpError::MissingRequiredLine("o="));
+    }
+
+    #[test]
+    fn test_session_description_clone() {
+        let input = "v=0\r\no=- 1 1 IN IP4 127.0.0.1\r\ns=-\r\nt=0 0\r\nm=audio 9 RTP/AVP 0\r\na=rtpmap:0 PCMU/8000\r\n";
+        let offer = SessionDescription::from_str(input).unwrap();
+        let mut answer = offer.clone();
+        assert_eq!(answer, offer);
+        answer.media_mut()[0].reject();
+        assert_ne!(answer, offer);
+        assert_eq!(offer.to_string(), input);
+    }
+
+    #[test]
+    fn test_session_description_reject_media() {
+        let input = "v=0\r\no=- 1 1 IN IP4 127.0.0.1\r\ns=-\r\nt=0 0\r\nm=audio 9 RTP/AVP 0\r\na=mid:0\r\nm=video 9/2 RTP/AVP 96\r\nb=AS:512\r\na=mid:1\r\na=rtpmap:96 VP8/90000\r\na=sendrecv\r\nm=application 9 UDP/DTLS/SCTP webrtc-datachannel\r\n";
+        let mut result = SessionDescription::from_str(input).unwrap();
+        result.media_mut()[1].reject();
+        assert!(result.media()[1].is_rejected());
+        assert!(!result.media()[0].is_rejected());
+        assert_eq!(result.to_string(), "v=0\r\no=- 1 1 IN IP4 127.0.0.1\r\ns=-\r\nt=0 0\r\nm=audio 9 RTP/AVP 0\r\na=mid:0\r\nm=video 0 RTP/AVP 96\r\na=mid:1\r\nm=application 9 UDP/DTLS/SCTP webrtc-datachannel\r\n");
+
+        result.retain_media(|media| media.media() != "application");
+        assert_eq!(result.media().len(), 2);
+        assert_eq!(result.to_string(), "v=0\r\no=- 1 1 IN IP4 127.0.0.1\r\ns=-\r\nt=0 0\r\nm=audio 9 RTP/AVP 0\r\na=mid:0\r\nm=video 0 RTP/AVP 96\r\na=mid:1\r\n");
+    }
+
+    #[test]
+    fn test_session_description_rtsp_control() {
+        let input = "v=0\r\no=- 1 1 IN IP4 127.0.0.1\r\ns=-\r\nt=0 0\r\na=control:rtsp://example.com/movie\r\nm=video 0 RTP/AVP 96\r\na=control:trackID=1\r\nm=audio 0 RTP/AVP 97\r\n";
+        let result = SessionDescription::from_str(input).unwrap();
+        assert_eq!(result.control(), Some("rtsp://example.com/movie"));
+        assert_eq!(result.media()[0].control(), Some("trackID=1"));
+        assert_eq!(result.media()[1].control(), None);
+        assert_eq!(result.to_string(), input);
+    }
+
+    #[test]
+    fn test_session_description_numeric_media_attributes() {
+        let input = "v=0\r\no=- 1 1 IN IP4 127.0.0.1\r\ns=-\r\nt=0 0\r\nm=video 9 RTP/AVP 96\r\na=framerate:29.97\r\na=quality:10\r\na=maxprate:15.5\r\na=label:main\r\n";
+        let result = SessionDescription::from_str(input).unwrap();
+        let media = &result.media()[0];
+        assert_eq!(media.attribute_f64("framerate"), Some(29.97));
+        assert_eq!(media.attribute_u32("framerate"), None);
+        assert_eq!(media.attribute_u32("quality"), Some(10));
+        assert_eq!(media.attribute_f64("quality"), Some(10.0));
+        assert_eq!(media.attribute_f64("maxprate"), Some(15.5));
+        assert_eq!(media.attribute_f64("label"), None);
+        assert_eq!(media.attribute_u32("orient"), None);
+    }
+
+    #[test]
+    fn test_session_description_framerate() {
+        for (input, expected) in [("29.97", 29.97), ("30", 30.0)] {
+            let input = format!("v=0\r\no=- 1 1 IN IP4 127.0.0.1\r\ns=-\r\nt=0 0\r\nm=video 9 RTP/AVP 96\r\na=framerate:{}\r\n", input);
+            let result = SessionDescription::from_str(&input).unwrap();
+            let media = &result.media()[0];
+            assert_eq!(media.framerate(), Some(expected));
+            assert_eq!(media.attribute_f64("framerate"), Some(expected));
+            assert_eq!(result.to_string(), input);
+        }
+    }
+
+    #[test]
+    fn test_session_description_typed_numeric_media_attributes() {
+        let input = "v=0\r\no=- 1 1 IN IP4 127.0.0.1\r\ns=-\r\nt=0 0\r\nm=audio 9 RTP/AVP 0\r\na=ptime:20\r\na=maxptime:120\r\n";
+        let result = SessionDescription::from_str(input).unwrap();
+        let media = &result.media()[0];
+        assert_eq!(media.attribute_u32("ptime"), Some(20));
+        assert_eq!(media.attribute_f64("ptime"), Some(20.0));
+        assert_eq!(media.attribute_u32("maxptime"), Some(120));
+        assert_eq!(media.attribute_f64("maxptime"), Some(120.0));
+    }
+
+    #[test]
+    fn test_session_description_with_imageattr() {
+        let input = "v=0\r\no=- 1 1 IN IP4 127.0.0.1\r\ns=-\r\nt=0 0\r\nm=video 9 RTP/AVP 96 97\r\na=imageattr:96 send [x=1280,y=720] recv [x=640,y=480]\r\na=imageattr:97 send * recv [x=[320:16:640],y=[240:16:480]]\r\n";
+        let result = SessionDescription::from_str(input).unwrap();
+        let imageattrs: Vec<_> = result.media()[0].imageattrs().collect();
+        assert_eq!(imageattrs.len(), 2);
+        assert_eq!(
+            imageattrs[0].send().unwrap()[0].height_bounds(),
+            Some((720, 720))
+        );
+        assert_eq!(imageattrs[1].send(), Some(&[][..]));
+        assert_eq!(
+            imageattrs[1].recv().unwrap()[0].width_bounds(),
+            Some((320, 640))
+        );
+        assert_eq!(result.to_string(), input);
+    }
+
+    #[test]
+    fn test_session_description_with_simulcast() {
+        let input = "v=0\r\no=- 1 1 IN IP4 127.0.0.1\r\ns=-\r\nt=0 0\r\nm=video 9 UDP/TLS/RTP/SAVPF 96 97\r\na=rid:hi send pt=96;max-width=1280\r\na=rid:mid send max-width=640\r\na=rid:lo send\r\na=simulcast:send hi;mid;lo\r\n";
+        let result = SessionDescription::from_str(input).unwrap();
+        let media = &result.media()[0];
+        let rids: Vec<_> = media
+            .rids()
+            .map(|rid| (rid.id(), rid.max_width()))
+            .collect();
+        assert_eq!(
+            rids,
+            vec![("hi", Some(1280)), ("mid", Some(640)), ("lo", None)]
+        );
+        let simulcast = media.simulcast().unwrap();
+        assert_eq!(simulcast.send(), [vec!["hi"], vec!["mid"], vec!["lo"]]);
+        assert_eq!(result.to_string(), input);
+    }
+
+    #[test]
+    fn test_session_description_attributes_by_name() {
+        let input = "v=0\r\no=- 1 1 IN IP4 127.0.0.1\r\ns=-\r\nt=0 0\r\na=tool:foo\r\na=recvonly\r\na=tool:bar\r\n";
+        let result = SessionDescription::from_str(input).unwrap();
+        let tools: Vec<_> = result
+            .attributes_by_name("tool")
+            .map(|attribute| attribute.value())
+            .collect();
+        assert_eq!(tools, vec![Some("foo"), Some("bar")]);
+        assert_eq!(result.attributes_by_name("recvonly").count(), 1);
+    }
+
+    #[test]
+    fn test_session_description_address_type_mismatch() {
+        let input = "v=0\r\no=- 1 1 IN IP4 ::1\r\ns=-\r\nt=0 0\r\n";
+        let result = SessionDescription::from_str(input);
+        assert_eq!(result.unwrap_err(), SdpError::AddressTypeMismatch);
+
+        let input = "v=0\r\no=- 1 1 IN IP4 127.0.0.1\r\ns=-\r\nt=0 0\r\nm=audio 9 RTP/AVP 0\r\nc=IN IP6 10.0.0.1\r\n";
+        let result = SessionDescription::from_str(input);
+        assert_eq!(result.unwrap_err(), SdpError::AddressTypeMismatch);
+    }
+
+    #[test]
+    fn test_session_description_unknown_nettype() {
+        let input = "v=0\r\no=- 1 1 ATM NSAP 127.0.0.1\r\ns=-\r\nt=0 0\r\n";
+        let result = SessionDescription::from_str(input);
+        assert!(matches!(
+            result.unwrap_err(),
+            SdpError::MalformedLine { line_type: 'o', .. }
+        ));
+    }
+
+    #[test]
+    fn test_session_description_unknown_lines() {
+        let input = "v=0\r\no=- 1 1 IN IP4 127.0.0.1\r\ns=-\r\nt=0 0\r\ny=yes\r\nm=audio 9 RTP/AVP 0\r\nx=vendor specific\r\n";
+        let result = SessionDescription::from_str(input).unwrap();
+        assert_eq!(result.unknown_lines(), &[('y', "yes")]);
+        assert_eq!(
+            result.media()[0].unknown_lines(),
+            &[('x', "vendor specific")]
+        );
+        assert_eq!(result.to_string(), input);
+
+        let input = "v=0\r\no=- 1 1 IN IP4 127.0.0.1\r\ns=-\r\nt=0 0\r\na=tool:x\r\ny=yes\r\na=recvonly\r\nm=audio 9 RTP/AVP 0\r\nx=first\r\na=rtcp-mux\r\nx=last\r\n";
+        let result = SessionDescription::from_str(input).unwrap();
+        assert_eq!(result.to_string(), input);
+    }
+
+    #[test]
+    fn test_session_description_time_zones() {
+        let input = "v=0\r\no=- 1 1 IN IP4 127.0.0.1\r\ns=-\r\nt=2873397496 2873404696\r\nr=7d 1h 0 25h\r\nz=2882844526 -1h 2898848070 0\r\na=recvonly\r\n";
+        let result = SessionDescription::from_str(input).unwrap();
+        assert_eq!(
+            result.time_zones(),
+            &[
+                TimeZone::new(2882844526, -3600),
+                TimeZone::new(2898848070, 0)
+            ]
+        );
+        assert!(result.unknown_lines().is_empty());
+        let output = result.to_string();
+        assert_eq!(output, "v=0\r\no=- 1 1 IN IP4 127.0.0.1\r\ns=-\r\nt=2873397496 2873404696\r\nr=604800 3600 0 90000\r\nz=2882844526 -3600 2898848070 0\r\na=recvonly\r\n");
+        assert_eq!(SessionDescription::from_str(&output).unwrap(), result);
+    }
+
+    #[test]
+    fn test_session_description_missing_session_name() {
+        let input = "v=0\r\no=jdoe 2890844526 2890842807 IN IP4 192.168.10.1\r\nt=0 0\r\n";
+        let result = SessionDescription::from_str(input);
+        assert_eq!(result.unwrap_err(), SdpError::MissingRequiredLine("s="));
+    }
+
+    #[test]
+    fn test_session_description_empty_session_name() {
+        let input = "v=0\r\no=- 1 1 IN IP4 127.0.0.1\r\ns=\r\nt=0 0\r\n";
+        let result = SessionDescription::from_str(input);
+        assert_eq!(result.unwrap_err(), SdpError::EmptySessionName);
+
+        for name in [" ", "-"] {
+            let input = format!("v=0\r\no=- 1 1 IN IP4 127.0.0.1\r\ns={}\r\nt=0 0\r\n", name);
+            let result = SessionDescription::from_str(&input).unwrap();
+            assert_eq!(result.session_name().name(), name);
         }
     }
+
+    #[test]
+    fn test_session_description_charset() {
+        let input =
+            "v=0\r\no=- 1 1 IN IP4 127.0.0.1\r\ns=Séance\r\nt=0 0\r\na=charset:ISO-8859-1\r\n";
+        let result = SessionDescription::from_str(input).unwrap();
+        assert_eq!(result.charset(), Some("ISO-8859-1"));
+        assert_eq!(result.validate_session_name(), Ok(()));
+
+        let input =
+            "v=0\r\no=- 1 1 IN IP4 127.0.0.1\r\ns=Séance\r\nt=0 0\r\na=charset:US-ASCII\r\n";
+        let result = SessionDescription::from_str(input).unwrap();
+        assert_eq!(
+            result.validate_session_name(),
+            Err(SdpError::InvalidSessionName)
+        );
+
+        let input = "v=0\r\no=- 1 1 IN IP4 127.0.0.1\r\ns=Séance\r\nt=0 0\r\na=charset:KOI8-R\r\n";
+        let result = SessionDescription::from_str(input).unwrap();
+        assert_eq!(
+            result.validate_session_name(),
+            Err(SdpError::UnsupportedCharset("KOI8-R".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_session_description_missing_time() {
+        let input = "v=0\r\no=jdoe 2890844526 2890842807 IN IP4 192.168.10.1\r\ns=SDP Seminar\r\n";
+        let result = SessionDescription::from_str(input);
+        assert_eq!(result.unwrap_err(), SdpError::MissingRequiredLine("t="));
+    }
+
+    #[test]
+    fn test_line_key() {
+        assert!(matches!(
+            SessionDescriptionKeys::from('v'),
+            SessionDescriptionKeys::Version
+        ));
+        assert!(matches!(
+            SessionDescriptionKeys::from('y'),
+            SessionDescriptionKeys::Unknown
+        ));
+    }
+
+    #[test]
+    fn test_session_description_lenient_malformed_attributes() {
+        let input = "v=0\r\no=- 1 1 IN IP4 127.0.0.1\r\ns=-\r\nt=0 0\r\nm=audio 9 RTP/AVP 0\r\na=rtpmap:0 PCMU\r\na=candidate:1 1 UDP 1 10.0.0.1 9 typ newtype\r\na=rtcp-mux\r\n";
+        assert!(matches!(
+            SessionDescription::from_str(input).unwrap_err(),
+            SdpError::MalformedLine { line_type: 'a', .. }
+        ));
+        let result =
+            SessionDescription::from_str_with_options(input, &ParseOptions::lenient()).unwrap();
+        assert_eq!(
+            result.media()[0].attributes(),
+            &[
+                Attribute::Value("rtpmap", "0 PCMU"),
+                Attribute::Value("candidate", "1 1 UDP 1 10.0.0.1 9 typ newtype"),
+                Attribute::Property("rtcp-mux"),
+            ]
+        );
+        assert_eq!(result.to_string(), input);
+    }
+
+    #[test]
+    fn test_session_description_trailing_garbage() {
+        let input = "v=0\r\no=- 1 1 IN IP4 127.0.0.1\r\ns=-\r\nt=0 0 junk\r\n";
+        assert_eq!(
+            SessionDescription::from_str(input).unwrap_err(),
+            SdpError::MalformedLine {
+                line_type: 't',
+                offset: 41,
+                line: "t=0 0 junk".to_string(),
+                detail: "End of file failed at ` junk`".to_string(),
+                rfc_reference: "RFC 8866 §5.9",
+            }
+        );
+
+        let input = "v=0\r\no=- 1 1 IN IP4 127.0.0.1\r\ns=-\r\nt=0 0\r\nm=audio 9 RTP/AVP 0\r\na=ptime:20ms\r\n";
+        assert!(matches!(
+            SessionDescription::from_str(input).unwrap_err(),
+            SdpError::MalformedLine { line_type: 'a', line, .. } if line == "a=ptime:20ms"
+        ));
+    }
 }
@@ -0,0 +1,219 @@
+use super::SessionDescription;
+use crate::attribute::{Attribute, RtpMap};
+use crate::error::SdpError;
+use crate::media_description::MediaDescription;
+
+impl<'a> SessionDescription<'a> {
+    /// Applies the choices an `answer` made to this offer, so that the offer describes the
+    /// negotiated session (RFC 3264 §6).
+    ///
+    /// The answer must have as many media sections as the offer, each of the same media
+    /// type, otherwise [`SdpError::MismatchedMedia`] is returned and the offer is untouched.
+    /// Then, for each media section:
+    /// - a section the answer rejected (port `0`), or that has no format in common with its
+    ///   answer, is [rejected](crate::MediaDescription::reject),
+    /// - otherwise only the formats the answer kept remain, along with their `a=rtpmap:` and
+    ///   `a=fmtp:` attributes, and the direction of the answer replaces the offered one.
+    ///
+    /// Dynamic payload types (96 to 127) are matched by the encoding, clock rate and channels
+    /// of their `a=rtpmap:`, since the answerer only SHOULD reuse the offered numbers (RFC
+    /// 3264 §6.1); the offered numbers are kept. The direction is reversed to describe the
+    /// offerer's side, e.g. the `recvonly` of an answer becomes `sendonly`.
+    pub fn apply_answer(&mut self, answer: &SessionDescription<'_>) -> Result<(), SdpError> {
+        self.media_lines_align_with(answer)?;
+        for (media, answered) in self.media.iter_mut().zip(&answer.media) {
+            let formats: Vec<&'a str> = media
+                .formats()
+                .iter()
+                .copied()
+                .filter(|format| {
+                    answered
+                        .formats()
+                        .iter()
+                        .any(|other| is_same_format(media, format, answered, other))
+                })
+                .collect();
+            if answered.is_rejected() || formats.is_empty() {
+                media.reject();
+                continue;
+            }
+            media.retain_formats(|format| formats.contains(format));
+            let kept = media.formats().to_vec();
+            media.attributes_mut().retain(|attribute| match attribute {
+                Attribute::RtpMap(rtpmap) => is_kept(&kept, rtpmap.payload_type()),
+                Attribute::Fmtp(fmtp) => is_kept(&kept, fmtp.payload_type()),
+                _ => true,
+            });
+            media.set_direction(answered.effective_direction(answer).reverse());
+        }
+        Ok(())
+    }
+
+    /// Checks that `other` has as many media sections as this session, in the same order of
+    /// media types, returning [`SdpError::MismatchedMedia`] with the first index that differs.
+    ///
+    /// An answer must keep the `m=` lines of its offer (RFC 3264 §6), so this is meant to
+    /// validate an incoming answer against the offer it replies to.
+    pub fn media_lines_align_with(&self, other: &SessionDescription<'_>) -> Result<(), SdpError> {
+        let index = self
+            .media
+            .iter()
+            .zip(&other.media)
+            .position(|(media, other)| media.media() != other.media())
+            .or_else(|| {
+                (self.media.len() != other.media.len())
+                    .then(|| self.media.len().min(other.media.len()))
+            });
+        match index {
+            Some(index) => Err(SdpError::MismatchedMedia(index)),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Returns `true` if `format` of `media` is the same media format as `other` of
+/// `other_media`: dynamic payload types by their `a=rtpmap:`, other formats by their value.
+fn is_same_format(
+    media: &MediaDescription<'_>,
+    format: &str,
+    other_media: &MediaDescription<'_>,
+    other: &str,
+) -> bool {
+    match (
+        dynamic_rtpmap(media, format),
+        dynamic_rtpmap(other_media, other),
+    ) {
+        (Some(rtpmap), Some(other)) => {
+            rtpmap
+                .encoding_name()
+                .eq_ignore_ascii_case(other.encoding_name())
+                && rtpmap.clock_rate() == other.clock_rate()
+                && rtpmap.channels().unwrap_or(1) == other.channels().unwrap_or(1)
+        }
+        _ => format == other,
+    }
+}
+
+/// Returns the `a=rtpmap:` of `format` if it is a dynamic payload type.
+fn dynamic_rtpmap<'m, 'a>(media: &'m MediaDescription<'a>, format: &str) -> Option<&'m RtpMap<'a>> {
+    let payload_type: u8 = format
+        .parse()
+        .ok()
+        .filter(|payload_type| (96..=127).contains(payload_type))?;
+    media
+        .rtpmaps()
+        .find(|rtpmap| rtpmap.payload_type() == payload_type)
+}
+
+fn is_kept(formats: &[&str], payload_type: u8) -> bool {
+    formats
+        .iter()
+        .any(|format| format.parse() == Ok(payload_type))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::attribute::Direction;
+
+    const OFFER: &str = "v=0\r\no=alice 1 1 IN IP4 10.0.0.1\r\ns=-\r\nc=IN IP4 10.0.0.1\r\nt=0 0\r\nm=audio 49170 RTP/AVP 0 8 97\r\na=rtpmap:0 PCMU/8000\r\na=rtpmap:8 PCMA/8000\r\na=rtpmap:97 iLBC/8000\r\na=fmtp:97 mode=30\r\nm=video 51372 RTP/AVP 31 32\r\na=rtpmap:31 H261/90000\r\na=rtpmap:32 MPV/90000\r\na=sendrecv\r\nm=text 11000 RTP/AVP 98\r\na=rtpmap:98 t140/1000\r\n";
+
+    #[test]
+    fn test_apply_answer() {
+        let answer = "v=0\r\no=bob 2 2 IN IP4 10.0.0.2\r\ns=-\r\nc=IN IP4 10.0.0.2\r\nt=0 0\r\nm=audio 49920 RTP/AVP 97 0\r\na=rtpmap:0 PCMU/8000\r\na=rtpmap:97 iLBC/8000\r\na=recvonly\r\nm=video 0 RTP/AVP 31\r\nm=text 11002 RTP/AVP 99\r\na=rtpmap:99 t140/1000\r\n";
+        let mut offer = SessionDescription::from_str(OFFER).unwrap();
+        let answer = SessionDescription::from_str(answer).unwrap();
+        offer.apply_answer(&answer).unwrap();
+
+        let audio = &offer.media()[0];
+        assert_eq!(audio.formats(), ["0", "97"]);
+        assert_eq!(audio.rtpmaps().count(), 2);
+        assert_eq!(audio.fmtps().next().unwrap().payload_type(), 97);
+        assert_eq!(audio.direction(), Some(Direction::SendOnly));
+        assert!(offer.media()[1].is_rejected());
+        assert_eq!(offer.media()[2].formats(), ["98"]);
+        assert_eq!(offer.to_string(), "v=0\r\no=alice 1 1 IN IP4 10.0.0.1\r\ns=-\r\nc=IN IP4 10.0.0.1\r\nt=0 0\r\nm=audio 49170 RTP/AVP 0 97\r\na=rtpmap:0 PCMU/8000\r\na=rtpmap:97 iLBC/8000\r\na=fmtp:97 mode=30\r\na=sendonly\r\nm=video 0 RTP/AVP 31 32\r\nm=text 11000 RTP/AVP 98\r\na=rtpmap:98 t140/1000\r\na=sendrecv\r\n");
+    }
+
+    #[test]
+    fn test_apply_answer_matches_dynamic_payload_types() {
+        let answer = "v=0\r\no=bob 2 2 IN IP4 10.0.0.2\r\ns=-\r\nc=IN IP4 10.0.0.2\r\nt=0 0\r\nm=audio 49920 RTP/AVP 96 97\r\na=rtpmap:96 ILBC/8000\r\na=rtpmap:97 opus/48000/2\r\nm=video 51372 RTP/AVP 31\r\nm=text 11002 RTP/AVP 98\r\na=rtpmap:98 red/1000\r\n";
+        let mut offer = SessionDescription::from_str(OFFER).unwrap();
+        let answer = SessionDescription::from_str(answer).unwrap();
+        offer.apply_answer(&answer).unwrap();
+        assert_eq!(offer.media()[0].formats(), ["97"]);
+        assert_eq!(offer.media()[1].formats(), ["31"]);
+        assert!(offer.media()[2].is_rejected());
+    }
+
+    #[test]
+    fn test_apply_answer_replaces_direction() {
+        let answer = "v=0\r\no=bob 2 2 IN IP4 10.0.0.2\r\ns=-\r\nc=IN IP4 10.0.0.2\r\nt=0 0\r\na=inactive\r\nm=audio 49920 RTP/AVP 0\r\nm=video 51372 RTP/AVP 32\r\nm=text 11002 RTP/AVP 98\r\na=sendonly\r\n";
+        let mut offer = SessionDescription::from_str(OFFER).unwrap();
+        let answer = SessionDescription::from_str(answer).unwrap();
+        offer.apply_answer(&answer).unwrap();
+        let directions: Vec<_> = offer
+            .media()
+            .iter()
+            .map(|media| media.direction())
+            .collect();
+        assert_eq!(
+            directions,
+            [
+                Some(Direction::Inactive),
+                Some(Direction::Inactive),
+                Some(Direction::RecvOnly)
+            ]
+        );
+        assert_eq!(offer.media()[1].attributes_by_name("inactive").count(), 1);
+        assert_eq!(offer.media()[1].attributes_by_name("sendrecv").count(), 0);
+    }
+
+    #[test]
+    fn test_media_lines_align_with() {
+        let offer = SessionDescription::from_str(OFFER).unwrap();
+        assert_eq!(offer.media_lines_align_with(&offer), Ok(()));
+        for (answer, index) in [
+            ("", 0),
+            ("m=video 51372 RTP/AVP 32\r\n", 0),
+            ("m=audio 0 RTP/AVP 0\r\nm=video 0 RTP/AVP 32\r\nm=text 0 RTP/AVP 98\r\nm=application 9 UDP/DTLS/SCTP webrtc-datachannel\r\n", 3),
+        ] {
+            let answer = format!(
+                "v=0\r\no=bob 2 2 IN IP4 10.0.0.2\r\ns=-\r\nc=IN IP4 10.0.0.2\r\nt=0 0\r\n{}",
+                answer
+            );
+            let answer = SessionDescription::from_str(&answer).unwrap();
+            assert_eq!(
+                offer.media_lines_align_with(&answer),
+                Err(SdpError::MismatchedMedia(index))
+            );
+        }
+        let answer = "v=0\r\no=bob 2 2 IN IP4 10.0.0.2\r\ns=-\r\nc=IN IP4 10.0.0.2\r\nt=0 0\r\nm=audio 0 RTP/AVP 0\r\nm=video 0 RTP/AVP 31\r\nm=text 0 RTP/AVP 98\r\n";
+        let answer = SessionDescription::from_str(answer).unwrap();
+        assert_eq!(offer.media_lines_align_with(&answer), Ok(()));
+        assert_eq!(
+            SdpError::MismatchedMedia(3).to_string(),
+            "media section 3 does not match between offer and answer"
+        );
+    }
+
+    #[test]
+    fn test_apply_answer_mismatched_media() {
+        for (answer, index) in [
+            ("m=audio 49920 RTP/AVP 0\r\nm=video 51372 RTP/AVP 32\r\n", 2),
+            ("m=audio 49920 RTP/AVP 0\r\nm=text 11002 RTP/AVP 98\r\nm=video 51372 RTP/AVP 32\r\n", 1),
+        ] {
+            let answer = format!(
+                "v=0\r\no=bob 2 2 IN IP4 10.0.0.2\r\ns=-\r\nc=IN IP4 10.0.0.2\r\nt=0 0\r\n{}",
+                answer
+            );
+            let mut offer = SessionDescription::from_str(OFFER).unwrap();
+            let answer = SessionDescription::from_str(&answer).unwrap();
+            assert_eq!(
+                offer.apply_answer(&answer),
+                Err(SdpError::MismatchedMedia(index))
+            );
+            assert_eq!(offer.to_string(), OFFER);
+        }
+    }
+}
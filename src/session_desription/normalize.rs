@@ -0,0 +1,158 @@
+use std::{cmp::Ordering, fmt};
+
+use super::SessionDescription;
+use crate::attribute::{Attribute, AttributeList, Fingerprint, RtpMap};
+
+impl SessionDescription<'_> {
+    /// Returns the session description in a canonical form, so that descriptions that only
+    /// differ in insignificant details serialize to the same bytes.
+    ///
+    /// On top of what [`fmt::Display`] already does (lines in RFC 8866 order, `\r\n` line
+    /// endings), the canonical form:
+    /// - sorts the attributes of each section by name, and the attributes of the same name
+    ///   by their canonical line where their order carries no meaning (`a=candidate:`,
+    ///   `a=ssrc:`, `a=extmap:`, `a=rtcp-fb:`, `a=rtpmap:` and `a=fmtp:`); the others keep
+    ///   their original order since it can be significant (e.g. `a=rid:`),
+    /// - lowercases the case-insensitive tokens: the hash function of `a=fingerprint:`, the
+    ///   encoding name of `a=rtpmap:` and the transport of `a=candidate:`,
+    /// - uppercases the hexadecimal digits of `a=fingerprint:`.
+    ///
+    /// Normalizing is idempotent: parsing and normalizing a canonical form yields it again.
+    pub fn normalize(&self) -> String {
+        Normalized(self).to_string()
+    }
+}
+
+struct Normalized<'s, 'a>(&'s SessionDescription<'a>);
+
+impl fmt::Display for Normalized<'_, '_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.write_lines(f, &write_attributes, false)
+    }
+}
+
+/// The attributes that may appear in any order among those of the same name.
+const UNORDERED_ATTRIBUTES: [&str; 6] =
+    ["candidate", "ssrc", "extmap", "rtcp-fb", "rtpmap", "fmtp"];
+
+/// Writes the attributes sorted and canonicalized, followed by the unknown lines, whose
+/// position among the attributes does not survive the sort.
+fn write_attributes(f: &mut fmt::Formatter<'_>, attributes: &AttributeList<'_>) -> fmt::Result {
+    let mut sorted: Vec<_> = attributes
+        .iter()
+        .map(|attribute| (attribute.name(), canonical_line(attribute)))
+        .collect();
+    sorted.sort_by(|(name, line), (other_name, other_line)| {
+        name.cmp(other_name).then_with(|| {
+            if UNORDERED_ATTRIBUTES.contains(name) {
+                line.cmp(other_line)
+            } else {
+                Ordering::Equal
+            }
+        })
+    });
+    for (_, line) in sorted {
+        f.write_str(&line)?;
+    }
+    for (line_type, value) in attributes.unknown_lines() {
+        write!(f, "{}={}\r\n", line_type, value)?;
+    }
+    Ok(())
+}
+
+fn canonical_line(attribute: &Attribute<'_>) -> String {
+    match attribute {
+        Attribute::Fingerprint(fingerprint) => {
+            let hash_function = fingerprint.hash_function().to_ascii_lowercase();
+            let digest = fingerprint.fingerprint().to_ascii_uppercase();
+            let fingerprint = Fingerprint::new(&hash_function, &digest);
+            Attribute::Fingerprint(fingerprint).to_string()
+        }
+        Attribute::RtpMap(rtpmap) => {
+            let encoding_name = rtpmap.encoding_name().to_ascii_lowercase();
+            let rtpmap = RtpMap::new(
+                rtpmap.payload_type(),
+                &encoding_name,
+                rtpmap.clock_rate(),
+                rtpmap.channels(),
+            );
+            Attribute::RtpMap(rtpmap).to_string()
+        }
+        Attribute::Candidate(candidate) => {
+            let transport = candidate.transport().to_ascii_lowercase();
+            let mut candidate = candidate.clone();
+            candidate.set_transport(&transport);
+            Attribute::Candidate(candidate).to_string()
+        }
+        attribute => attribute.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SESSION: &str = "v=0\r\no=- 1 1 IN IP4 127.0.0.1\r\ns=-\r\nt=0 0\r\n";
+
+    #[test]
+    fn test_normalize() {
+        let input = format!("{}a=fingerprint:SHA-256 ab:cd:EF\r\na=ice-lite\r\nm=audio 9 UDP/TLS/RTP/SAVPF 111 0\r\na=rtpmap:111 OPUS/48000/2\r\na=mid:0\r\na=rtpmap:0 PCMU/8000\r\na=candidate:1 1 UDP 2130706431 10.0.0.1 9 typ host generation 0\r\n", SESSION);
+        let session = SessionDescription::from_str(&input).unwrap();
+        assert_eq!(
+            session.normalize(),
+            format!("{}a=fingerprint:sha-256 AB:CD:EF\r\na=ice-lite\r\nm=audio 9 UDP/TLS/RTP/SAVPF 111 0\r\na=candidate:1 1 udp 2130706431 10.0.0.1 9 typ host generation 0\r\na=mid:0\r\na=rtpmap:0 pcmu/8000\r\na=rtpmap:111 opus/48000/2\r\n", SESSION)
+        );
+    }
+
+    #[test]
+    fn test_normalize_equivalent_descriptions() {
+        let first = format!(
+            "{}m=audio 9 RTP/AVP 0\na=rtcp-mux\na=rtpmap:0 PCMU/8000\n",
+            SESSION
+        );
+        let second = format!(
+            "{}m=audio 9 RTP/AVP 0\r\na=rtpmap:0 pcmu/8000\r\na=rtcp-mux\r\n",
+            SESSION
+        );
+        let first = SessionDescription::from_str(&first).unwrap();
+        let second = SessionDescription::from_str(&second).unwrap();
+        assert_ne!(first, second);
+        assert_eq!(first.normalize(), second.normalize());
+    }
+
+    #[test]
+    fn test_normalize_unordered_attributes() {
+        let candidates = [
+            "a=candidate:1 1 udp 2130706431 10.0.0.1 9 typ host\r\n",
+            "a=candidate:2 1 UDP 1694498815 192.0.2.1 9 typ srflx raddr 10.0.0.1 rport 9\r\n",
+        ];
+        let first = format!(
+            "{}m=audio 9 RTP/AVP 0\r\na=rid:hi send\r\na=rid:lo send\r\n{}{}",
+            SESSION, candidates[0], candidates[1]
+        );
+        let second = format!(
+            "{}m=audio 9 RTP/AVP 0\r\n{}a=rid:hi send\r\n{}a=rid:lo send\r\n",
+            SESSION, candidates[1], candidates[0]
+        );
+        let first = SessionDescription::from_str(&first).unwrap();
+        let second = SessionDescription::from_str(&second).unwrap();
+        assert_eq!(first.normalize(), second.normalize());
+
+        let swapped = format!(
+            "{}m=audio 9 RTP/AVP 0\r\na=rid:lo send\r\na=rid:hi send\r\n",
+            SESSION
+        );
+        let swapped = SessionDescription::from_str(&swapped).unwrap();
+        assert!(swapped
+            .normalize()
+            .contains("a=rid:lo send\r\na=rid:hi send\r\n"));
+    }
+
+    #[test]
+    fn test_normalize_idempotent() {
+        let input = format!("{}a=tool:x\r\na=fingerprint:SHA-1 0a:0b\r\nm=video 9 RTP/AVP 96\r\na=rtpmap:96 VP8/90000\r\na=extmap:1 urn:ietf:params:rtp-hdrext:toffset\r\na=candidate:1 1 TCP 1 ::1 9 typ host\r\n", SESSION);
+        let normalized = SessionDescription::from_str(&input).unwrap().normalize();
+        let session = SessionDescription::from_str(&normalized).unwrap();
+        assert_eq!(session.normalize(), normalized);
+    }
+}
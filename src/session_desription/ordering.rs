@@ -0,0 +1,121 @@
+use crate::error::SdpError;
+
+/// The `<type>`s of the session-level lines, in the order RFC 8866 requires them.
+const SESSION_ORDER: &str = "vosiuepcbtrzka";
+/// The `<type>`s of the lines of a media description, in the order RFC 8866 requires them.
+const MEDIA_ORDER: &str = "micbka";
+
+/// Checks that the lines of `input` appear in the order fixed by RFC 8866: `v=`, `o=`, `s=`,
+/// the optional session-level lines, the time descriptions and then the media descriptions.
+///
+/// Lines of a type this crate does not know about are ignored.
+///
+/// see https://tools.ietf.org/html/rfc8866#section-5
+pub(super) fn validate_ordering(input: &str) -> Result<(), SdpError> {
+    let mut in_media = false;
+    let mut previous: Option<char> = None;
+    for found in input.lines().filter_map(|line| line.chars().next()) {
+        let (order, repeatable, mandatory) = if in_media {
+            (MEDIA_ORDER, "cba", "")
+        } else {
+            (SESSION_ORDER, "epbtra", "vost")
+        };
+        // Index in `order` of the line type following the previous line, and of the first
+        // line type allowed after it.
+        let (next, earliest) = match previous.and_then(|key| order.find(key)) {
+            Some(index) if repeatable.contains(order.as_bytes()[index] as char) => {
+                (index + 1, index)
+            }
+            Some(index) => (index + 1, index + 1),
+            None => (0, 0),
+        };
+        let expected = order[earliest..].chars().next().unwrap_or('m');
+        // An `m=` line closes the session level or the previous media description.
+        let index = match (found, order.find(found)) {
+            ('m', _) => order.len(),
+            (_, Some(index)) => index,
+            (_, None) if SESSION_ORDER.contains(found) => {
+                return Err(SdpError::OutOfOrderLine { expected, found })
+            }
+            (_, None) => continue,
+        };
+        // A time description may follow the repeat times of the previous one.
+        let is_next_time = found == 't' && previous == Some('r');
+        if index < earliest && !is_next_time {
+            return Err(SdpError::OutOfOrderLine { expected, found });
+        }
+        let skipped = order.get(next..index).unwrap_or_default();
+        if let Some(skipped) = skipped.chars().find(|&key| mandatory.contains(key)) {
+            return Err(SdpError::OutOfOrderLine {
+                expected: skipped,
+                found,
+            });
+        }
+        in_media |= found == 'm';
+        previous = Some(found);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_ordering() {
+        let input = "v=0\r\no=- 1 1 IN IP4 127.0.0.1\r\ns=-\r\ni=info\r\ne=a@example.com\r\ne=b@example.com\r\nc=IN IP4 127.0.0.1\r\nt=0 0\r\nr=7d 1h 0\r\nt=0 0\r\na=tool:foo\r\nm=audio 9 RTP/AVP 0\r\ni=audio\r\nc=IN IP4 127.0.0.1\r\na=mid:0\r\nm=video 9 RTP/AVP 96\r\na=mid:1\r\n";
+        assert_eq!(validate_ordering(input), Ok(()));
+    }
+
+    #[test]
+    fn test_validate_ordering_missing_mandatory_line() {
+        assert_eq!(
+            validate_ordering("o=- 1 1 IN IP4 127.0.0.1\r\n"),
+            Err(SdpError::OutOfOrderLine {
+                expected: 'v',
+                found: 'o'
+            })
+        );
+        assert_eq!(
+            validate_ordering("v=0\r\no=- 1 1 IN IP4 127.0.0.1\r\ns=-\r\nm=audio 9 RTP/AVP 0\r\n"),
+            Err(SdpError::OutOfOrderLine {
+                expected: 't',
+                found: 'm'
+            })
+        );
+    }
+
+    #[test]
+    fn test_validate_ordering_out_of_order() {
+        assert_eq!(
+            validate_ordering(
+                "v=0\r\no=- 1 1 IN IP4 127.0.0.1\r\ns=-\r\nt=0 0\r\nc=IN IP4 127.0.0.1\r\n"
+            ),
+            Err(SdpError::OutOfOrderLine {
+                expected: 't',
+                found: 'c'
+            })
+        );
+        assert_eq!(
+            validate_ordering("v=0\r\no=- 1 1 IN IP4 127.0.0.1\r\ns=-\r\ns=-\r\nt=0 0\r\n"),
+            Err(SdpError::OutOfOrderLine {
+                expected: 'i',
+                found: 's'
+            })
+        );
+        assert_eq!(
+            validate_ordering("v=0\r\no=- 1 1 IN IP4 127.0.0.1\r\ns=-\r\nt=0 0\r\nm=audio 9 RTP/AVP 0\r\na=mid:0\r\nc=IN IP4 127.0.0.1\r\n"),
+            Err(SdpError::OutOfOrderLine {
+                expected: 'a',
+                found: 'c'
+            })
+        );
+        assert_eq!(
+            validate_ordering("v=0\r\no=- 1 1 IN IP4 127.0.0.1\r\ns=-\r\nt=0 0\r\nm=audio 9 RTP/AVP 0\r\nt=0 0\r\n"),
+            Err(SdpError::OutOfOrderLine {
+                expected: 'i',
+                found: 't'
+            })
+        );
+    }
+}
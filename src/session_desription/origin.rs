@@ -1,28 +1,36 @@
 use nom::{
     branch::alt,
-    bytes::complete::tag,
-    character::{
-        complete::{alpha1, alphanumeric1, digit1, line_ending, multispace1, u64, u8},
-        streaming::not_line_ending,
-    },
-    combinator::map,
-    error::ParseError,
-    sequence::{terminated, tuple},
+    bytes::complete::{tag, take_till1},
+    character::complete::{alpha1, alphanumeric1, digit1, line_ending, multispace1, u64},
+    combinator::{eof, map_res},
+    error::{FromExternalError, ParseError},
+    sequence::terminated,
     IResult, Parser,
 };
 use std::{
+    borrow::Cow,
+    fmt,
     net::{IpAddr, Ipv4Addr},
+    num::ParseIntError,
     str::FromStr,
+    sync::atomic::{AtomicU64, Ordering},
 };
 
-#[derive(Debug)]
+use super::address::{parse_connection_address, ConnectionAddress};
+use super::Rebind;
+use crate::error::SdpError;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Origin<'a> {
     username: &'a str,
-    session_id: &'a str,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    session_id: Cow<'a, str>,
     session_version: u64,
     nettype: NetType,
     addrtype: AddrType,
-    unicast_address: IpAddr,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    unicast_address: ConnectionAddress<'a>,
 }
 
 impl<'a> Origin<'a> {
@@ -32,39 +40,244 @@ impl<'a> Origin<'a> {
         session_version: u64,
         nettype: NetType,
         addrtype: AddrType,
-        unicast_address: IpAddr,
+        unicast_address: ConnectionAddress<'a>,
     ) -> Self {
         Self {
             username,
-            session_id,
+            session_id: Cow::Borrowed(session_id),
             session_version,
             nettype,
             addrtype,
             unicast_address,
         }
     }
-}
 
-impl PartialEq for Origin<'_> {
-    fn eq(&self, other: &Self) -> bool {
+    /// Like [`Origin::new`], but fails with [`SdpError::AddressTypeMismatch`] if `addrtype`
+    /// contradicts the family of `unicast_address`.
+    pub fn try_new(
+        username: &'a str,
+        session_id: &'a str,
+        session_version: u64,
+        nettype: NetType,
+        addrtype: AddrType,
+        unicast_address: ConnectionAddress<'a>,
+    ) -> Result<Self, SdpError> {
+        if !addrtype.matches(&unicast_address) {
+            return Err(SdpError::AddressTypeMismatch);
+        }
+        Ok(Self::new(
+            username,
+            session_id,
+            session_version,
+            nettype,
+            addrtype,
+            unicast_address,
+        ))
+    }
+
+    /// Returns a builder for an origin, with defaults for every field, see [`OriginBuilder`].
+    pub fn builder() -> OriginBuilder<'a> {
+        OriginBuilder::default()
+    }
+
+    pub fn username(&self) -> &'a str {
+        self.username
+    }
+
+    /// Returns the session id. A parsed origin borrows it from the input, a built one may
+    /// hold a generated id.
+    pub fn session_id(&self) -> &str {
+        &self.session_id
+    }
+
+    /// Returns the session id as a number.
+    ///
+    /// RFC 8866 only recommends that the session id fits in 64 bits, so this fails rather
+    /// than overflow if it does not. [`Origin::session_id`] is always available.
+    pub fn session_id_u64(&self) -> Result<u64, ParseIntError> {
+        self.session_id.parse()
+    }
+
+    pub fn session_version(&self) -> u64 {
+        self.session_version
+    }
+
+    pub fn nettype(&self) -> &NetType {
+        &self.nettype
+    }
+
+    pub fn addrtype(&self) -> &AddrType {
+        &self.addrtype
+    }
+
+    pub fn unicast_address(&self) -> &ConnectionAddress<'a> {
+        &self.unicast_address
+    }
+
+    /// Returns `true` if both origins belong to the same session and this one has a greater
+    /// session version, i.e. it describes a modification of `other` (RFC 3264 §8).
+    ///
+    /// The session is identified by the origin fields other than the session version (RFC
+    /// 8866 §5.2). A different session is never newer, whatever its version.
+    pub fn is_newer_than(&self, other: &Origin<'_>) -> bool {
+        self.is_same_session(other) && self.session_version > other.session_version
+    }
+
+    fn is_same_session(&self, other: &Origin<'_>) -> bool {
         self.username == other.username
             && self.session_id == other.session_id
-            && self.session_version == other.session_version
             && self.nettype == other.nettype
             && self.addrtype == other.addrtype
             && self.unicast_address == other.unicast_address
     }
 }
 
-#[derive(Debug)]
+impl Rebind for Origin<'_> {
+    type Output<'b> = Origin<'b>;
+
+    fn rebind<'b>(&self, strings: &mut dyn FnMut(&str) -> &'b str) -> Origin<'b> {
+        Origin {
+            username: strings(self.username),
+            session_id: Cow::Borrowed(strings(&self.session_id)),
+            session_version: self.session_version,
+            nettype: self.nettype.clone(),
+            addrtype: self.addrtype.clone(),
+            unicast_address: self.unicast_address.rebind(strings),
+        }
+    }
+}
+
+impl fmt::Display for Origin<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "o={} {} {} {} {} {}\r\n",
+            self.username,
+            self.session_id,
+            self.session_version,
+            self.nettype,
+            self.addrtype,
+            self.unicast_address
+        )
+    }
+}
+
+/// Builds an [`Origin`] field by field, e.g. for the `o=` line of an answer.
+///
+/// Every field has a default:
+/// - `-` for the username,
+/// - a numeric session id from a process-wide counter, which is unique within the process
+///   only; set one explicitly when it must be globally unique (RFC 8866 §5.2),
+/// - `1` for the session version,
+/// - `IN` for the network type,
+/// - `127.0.0.1` for the address, and the address type of the address (`IP4` for a
+///   domain name).
+///
+/// # Example
+///
+/// ```
+/// use sdp_parser::{AddrType, ConnectionAddress, Origin};
+///
+/// let origin = Origin::builder()
+///     .session_version(2)
+///     .unicast_address(ConnectionAddress::Ip("::1".parse().unwrap()))
+///     .build()
+///     .unwrap();
+/// assert_eq!(origin.addrtype(), &AddrType::IP6);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct OriginBuilder<'a> {
+    username: Option<&'a str>,
+    session_id: Option<&'a str>,
+    session_version: Option<u64>,
+    nettype: Option<NetType>,
+    addrtype: Option<AddrType>,
+    unicast_address: Option<ConnectionAddress<'a>>,
+}
+
+impl<'a> OriginBuilder<'a> {
+    pub fn username(mut self, username: &'a str) -> Self {
+        self.username = Some(username);
+        self
+    }
+
+    /// Sets the session id, which must be numeric.
+    pub fn session_id(mut self, session_id: &'a str) -> Self {
+        self.session_id = Some(session_id);
+        self
+    }
+
+    pub fn session_version(mut self, session_version: u64) -> Self {
+        self.session_version = Some(session_version);
+        self
+    }
+
+    pub fn nettype(mut self, nettype: NetType) -> Self {
+        self.nettype = Some(nettype);
+        self
+    }
+
+    /// Sets the address type, instead of inferring it from the address.
+    pub fn addrtype(mut self, addrtype: AddrType) -> Self {
+        self.addrtype = Some(addrtype);
+        self
+    }
+
+    pub fn unicast_address(mut self, unicast_address: impl Into<ConnectionAddress<'a>>) -> Self {
+        self.unicast_address = Some(unicast_address.into());
+        self
+    }
+
+    /// Builds the origin.
+    ///
+    /// Fails with [`SdpError::AddressTypeMismatch`] if the address type was set and
+    /// contradicts the family of the address, or with [`SdpError::InvalidSessionId`] if the
+    /// session id was set and is not numeric.
+    pub fn build(self) -> Result<Origin<'a>, SdpError> {
+        let unicast_address = self
+            .unicast_address
+            .unwrap_or(ConnectionAddress::Ip(IpAddr::V4(Ipv4Addr::LOCALHOST)));
+        let addrtype = match (self.addrtype, &unicast_address) {
+            (Some(addrtype), address) if !addrtype.matches(address) => {
+                return Err(SdpError::AddressTypeMismatch)
+            }
+            (Some(addrtype), _) => addrtype,
+            (None, ConnectionAddress::Ip(address)) => AddrType::from_addr(address),
+            (None, ConnectionAddress::Fqdn(_)) => AddrType::IP4,
+        };
+        let session_id = match self.session_id {
+            Some(id) if !id.is_empty() && id.bytes().all(|b| b.is_ascii_digit()) => {
+                Cow::Borrowed(id)
+            }
+            Some(_) => return Err(SdpError::InvalidSessionId),
+            None => Cow::Owned(next_session_id().to_string()),
+        };
+        Ok(Origin {
+            username: self.username.unwrap_or("-"),
+            session_id,
+            session_version: self.session_version.unwrap_or(1),
+            nettype: self.nettype.unwrap_or(NetType::IN),
+            addrtype,
+            unicast_address,
+        })
+    }
+}
+
+fn next_session_id() -> u64 {
+    static NEXT_SESSION_ID: AtomicU64 = AtomicU64::new(1);
+    NEXT_SESSION_ID.fetch_add(1, Ordering::Relaxed)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum NetType {
     IN,
 }
 
-impl PartialEq for NetType {
-    fn eq(&self, other: &Self) -> bool {
-        match (self, other) {
-            (NetType::IN, NetType::IN) => true,
+impl fmt::Display for NetType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NetType::IN => write!(f, "IN"),
         }
     }
 }
@@ -83,18 +296,40 @@ impl FromStr for NetType {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AddrType {
     IP4,
     IP6,
 }
 
-impl PartialEq for AddrType {
-    fn eq(&self, other: &Self) -> bool {
-        match (self, other) {
-            (AddrType::IP4, AddrType::IP4) => true,
-            (AddrType::IP6, AddrType::IP6) => true,
-            _ => false,
+impl AddrType {
+    /// Returns the address type of the family of `address`.
+    pub fn from_addr(address: &IpAddr) -> AddrType {
+        match address {
+            IpAddr::V4(_) => AddrType::IP4,
+            IpAddr::V6(_) => AddrType::IP6,
+        }
+    }
+
+    /// Returns `true` if `address` is of this address type. Domain names match either type.
+    ///
+    /// IPv4-mapped IPv6 addresses (e.g. `::ffff:192.168.1.1`) are IPv6 addresses, so they
+    /// match `IP6` and not `IP4`.
+    pub fn matches(&self, address: &ConnectionAddress<'_>) -> bool {
+        match (self, address) {
+            (_, ConnectionAddress::Fqdn(_)) => true,
+            (AddrType::IP4, ConnectionAddress::Ip(address)) => address.is_ipv4(),
+            (AddrType::IP6, ConnectionAddress::Ip(address)) => address.is_ipv6(),
+        }
+    }
+}
+
+impl fmt::Display for AddrType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AddrType::IP4 => write!(f, "IP4"),
+            AddrType::IP6 => write!(f, "IP6"),
         }
     }
 }
@@ -114,8 +349,9 @@ impl FromStr for AddrType {
     }
 }
 
+/// The username is any token without whitespace, `-` if the host does not support user ids.
 fn parse_username<'i, E: ParseError<&'i str>>(input: &'i str) -> IResult<&'i str, &'i str, E> {
-    terminated(alt((alphanumeric1, tag("-"))), multispace1).parse(input)
+    terminated(take_till1(|c: char| c.is_whitespace()), multispace1).parse(input)
 }
 
 fn parse_session_id<'i, E: ParseError<&'i str>>(input: &'i str) -> IResult<&'i str, &'i str, E> {
@@ -126,57 +362,54 @@ fn parse_session_version<'i, E: ParseError<&'i str>>(input: &'i str) -> IResult<
     terminated(u64, multispace1).parse(input)
 }
 
-fn parse_nettype<'i, E: ParseError<&'i str>>(input: &'i str) -> IResult<&'i str, NetType, E> {
-    terminated(
-        map(alpha1, |s: &str| NetType::from_str(s).unwrap()),
-        multispace1,
-    )
-    .parse(input)
+pub(super) fn parse_nettype<
+    'i,
+    E: ParseError<&'i str> + FromExternalError<&'i str, ParseNetTypeError>,
+>(
+    input: &'i str,
+) -> IResult<&'i str, NetType, E> {
+    terminated(map_res(alpha1, NetType::from_str), multispace1).parse(input)
 }
 
-fn parse_addrtype<'i, E: ParseError<&'i str>>(input: &'i str) -> IResult<&'i str, AddrType, E> {
-    terminated(
-        map(alphanumeric1, |s: &str| AddrType::from_str(s).unwrap()),
-        multispace1,
-    )
-    .parse(input)
+pub(super) fn parse_addrtype<
+    'i,
+    E: ParseError<&'i str> + FromExternalError<&'i str, ParseAddrTypeError>,
+>(
+    input: &'i str,
+) -> IResult<&'i str, AddrType, E> {
+    terminated(map_res(alphanumeric1, AddrType::from_str), multispace1).parse(input)
 }
 
-fn parse_ip_address<'i, E: ParseError<&'i str>>(input: &'i str) -> IResult<&'i str, IpAddr, E> {
-    alt((
-        map(
-            tuple((
-                terminated(u8, tag(".")),
-                terminated(u8, tag(".")),
-                terminated(u8, tag(".")),
-                terminated(u8, line_ending),
-            )),
-            |(a, b, c, d)| IpAddr::V4(Ipv4Addr::new(a, b, c, d)),
-        ),
-        map(terminated(not_line_ending, line_ending), |s: &str| {
-            s.parse::<IpAddr>().unwrap()
-        }),
-    ))
-    .parse(input)
+fn parse_unicast_address<'i, E: ParseError<&'i str>>(
+    input: &'i str,
+) -> IResult<&'i str, ConnectionAddress<'i>, E> {
+    terminated(parse_connection_address, alt((line_ending, eof))).parse(input)
 }
 
 /// o=<username> <sess-id> <sess-version> <nettype> <addrtype> <unicast-address>
 /// o=jdoe 2890844526 2890842807 IN IP4
 /// see https://tools.ietf.org/html/rfc8866#section-5.2
-pub fn parse_origin<'i, E: ParseError<&'i str>>(input: &'i str) -> IResult<&'i str, Origin, E> {
+pub fn parse_origin<
+    'i,
+    E: ParseError<&'i str>
+        + FromExternalError<&'i str, ParseNetTypeError>
+        + FromExternalError<&'i str, ParseAddrTypeError>,
+>(
+    input: &'i str,
+) -> IResult<&'i str, Origin<'i>, E> {
     let (tail, _) = tag("o=").parse(input)?;
     let (tail, username) = parse_username(tail)?;
     let (tail, session_id) = parse_session_id(tail)?;
     let (tail, session_version) = parse_session_version(tail)?;
     let (tail, nettype) = parse_nettype(tail)?;
     let (tail, addrtype) = parse_addrtype(tail)?;
-    let (tail, unicast_address) = parse_ip_address(tail)?;
+    let (tail, unicast_address) = parse_unicast_address(tail)?;
 
     Ok((
         tail,
         Origin {
-            username: username,
-            session_id: session_id,
+            username,
+            session_id: Cow::Borrowed(session_id),
             session_version,
             nettype,
             addrtype,
@@ -187,7 +420,7 @@ pub fn parse_origin<'i, E: ParseError<&'i str>>(input: &'i str) -> IResult<&'i s
 
 #[cfg(test)]
 mod tests {
-    use std::net::Ipv6Addr;
+    use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 
     use super::*;
 
@@ -198,6 +431,93 @@ mod tests {
         assert_eq!(value, "jdoe");
     }
 
+    #[test]
+    fn test_parse_username_with_punctuation() {
+        for username in ["first.last", "user_name", "user@host", "a+b"] {
+            let input = format!("{} 123", username);
+            let (tail, value) = parse_username::<()>(&input).unwrap();
+            assert_eq!(tail, "123");
+            assert_eq!(value, username);
+        }
+        assert!(parse_username::<()>(" 123").is_err());
+    }
+
+    #[test]
+    fn test_parse_origin_dotted_username() {
+        let (_, value) = parse_origin::<()>("o=first.last 1 1 IN IP4 1.2.3.4\r\n").unwrap();
+        assert_eq!(value.username, "first.last");
+    }
+
+    #[test]
+    fn test_parse_anonymous_username() {
+        let (tail, value) = parse_username::<()>("- 123").unwrap();
+        assert_eq!(tail, "123");
+        assert_eq!(value, "-");
+
+        let (tail, value) = parse_username::<()>("-foo 123").unwrap();
+        assert_eq!(tail, "123");
+        assert_eq!(value, "-foo");
+    }
+
+    #[test]
+    fn test_parse_webrtc_origin() {
+        let (tail, value) =
+            parse_origin::<()>("o=- 4611731400430051336 2 IN IP4 127.0.0.1\r\n").unwrap();
+        assert_eq!(tail, "");
+        assert_eq!(value.username, "-");
+        assert_eq!(value.session_id, "4611731400430051336");
+        assert_eq!(value.session_version, 2);
+        assert_eq!(
+            value.unicast_address,
+            ConnectionAddress::Ip(IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)))
+        );
+    }
+
+    #[test]
+    fn test_parse_ipv4_mapped_ipv6_origin() {
+        let input = "o=- 1 1 IN IP6 ::ffff:192.168.1.1\r\n";
+        let (tail, value) = parse_origin::<()>(input).unwrap();
+        assert_eq!(tail, "");
+        let address = Ipv4Addr::new(192, 168, 1, 1).to_ipv6_mapped();
+        assert_eq!(
+            value.unicast_address,
+            ConnectionAddress::Ip(IpAddr::V6(address))
+        );
+        assert!(AddrType::IP6.matches(&value.unicast_address));
+        assert!(!AddrType::IP4.matches(&value.unicast_address));
+        assert_eq!(value.to_string(), input);
+    }
+
+    #[test]
+    fn test_origin_is_newer_than() {
+        let parse = |input| parse_origin::<()>(input).unwrap().1;
+        let offer = parse("o=alice 2890844526 2890844526 IN IP4 10.0.0.1\r\n");
+        let reoffer = parse("o=alice 2890844526 2890844527 IN IP4 10.0.0.1\r\n");
+        assert!(reoffer.is_newer_than(&offer));
+        assert!(!offer.is_newer_than(&reoffer));
+        assert!(!offer.is_newer_than(&offer));
+
+        for other in [
+            "o=alice 2890844999 2890844600 IN IP4 10.0.0.1\r\n",
+            "o=bob 2890844526 2890844600 IN IP4 10.0.0.1\r\n",
+            "o=alice 2890844526 2890844600 IN IP4 10.0.0.2\r\n",
+        ] {
+            assert!(!parse(other).is_newer_than(&offer), "{}", other);
+        }
+    }
+
+    #[test]
+    fn test_session_id_u64() {
+        let (_, value) =
+            parse_origin::<()>("o=- 4611731400430051336 2 IN IP4 127.0.0.1\r\n").unwrap();
+        assert_eq!(value.session_id_u64(), Ok(4611731400430051336));
+
+        let (_, value) =
+            parse_origin::<()>("o=- 18446744073709551616 2 IN IP4 127.0.0.1\r\n").unwrap();
+        assert_eq!(value.session_id(), "18446744073709551616");
+        assert!(value.session_id_u64().is_err());
+    }
+
     #[test]
     fn test_parse_session_id() {
         let (tail, value) = parse_session_id::<()>("123 456").unwrap();
@@ -219,6 +539,14 @@ mod tests {
         assert_eq!(value, NetType::IN);
     }
 
+    #[test]
+    fn test_parse_unknown_nettype() {
+        assert!(parse_nettype::<()>("ATM 123").is_err());
+        assert!(
+            parse_origin::<()>("o=jdoe 2890844526 2890842807 XX IP4 192.168.10.1\r\n").is_err()
+        );
+    }
+
     #[test]
     fn test_parse_addrtype() {
         let (tail, value) = parse_addrtype::<()>("IP4 123").unwrap();
@@ -226,6 +554,123 @@ mod tests {
         assert_eq!(value, AddrType::IP4);
     }
 
+    #[test]
+    fn test_parse_unknown_addrtype() {
+        assert!(parse_addrtype::<()>("IP5 123").is_err());
+        assert!(
+            parse_origin::<()>("o=jdoe 2890844526 2890842807 IN IPX 192.168.10.1\r\n").is_err()
+        );
+    }
+
+    #[test]
+    fn test_nettype_and_addrtype_hash() {
+        use std::collections::HashSet;
+
+        let set: HashSet<_> = [
+            (NetType::IN, AddrType::IP4),
+            (NetType::IN, AddrType::IP6),
+            (NetType::IN, AddrType::IP4),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(set.len(), 2);
+        assert!(set.contains(&(NetType::IN, AddrType::IP6)));
+    }
+
+    #[test]
+    fn test_addrtype_matches() {
+        let ipv4 = ConnectionAddress::Ip(IpAddr::V4(Ipv4Addr::new(192, 168, 10, 1)));
+        let ipv6 = ConnectionAddress::Ip(IpAddr::V6(Ipv6Addr::new(0, 0, 0, 0, 0, 0, 0, 1)));
+        let fqdn = ConnectionAddress::Fqdn("host.example.com");
+        assert!(AddrType::IP4.matches(&ipv4));
+        assert!(!AddrType::IP4.matches(&ipv6));
+        assert!(AddrType::IP6.matches(&ipv6));
+        assert!(!AddrType::IP6.matches(&ipv4));
+        assert!(AddrType::IP4.matches(&fqdn) && AddrType::IP6.matches(&fqdn));
+    }
+
+    #[test]
+    fn test_addrtype_from_addr() {
+        assert_eq!(
+            AddrType::from_addr(&IpAddr::V4(Ipv4Addr::LOCALHOST)),
+            AddrType::IP4
+        );
+        assert_eq!(
+            AddrType::from_addr(&IpAddr::V6(Ipv6Addr::LOCALHOST)),
+            AddrType::IP6
+        );
+    }
+
+    #[test]
+    fn test_origin_builder() {
+        let origin = Origin::builder().build().unwrap();
+        assert_eq!(origin.username(), "-");
+        assert!(origin.session_id_u64().is_ok());
+        assert_eq!(
+            origin.to_string(),
+            format!("o=- {} 1 IN IP4 127.0.0.1\r\n", origin.session_id())
+        );
+        assert_eq!(origin.session_version(), 1);
+        assert_eq!(origin.nettype(), &NetType::IN);
+        assert_eq!(origin.addrtype(), &AddrType::IP4);
+        assert_eq!(
+            origin.unicast_address(),
+            &ConnectionAddress::Ip(IpAddr::V4(Ipv4Addr::LOCALHOST))
+        );
+        let other = Origin::builder().build().unwrap();
+        assert_ne!(origin.session_id(), other.session_id());
+
+        let origin = Origin::builder()
+            .username("bob")
+            .session_id("2890844526")
+            .session_version(2890842807)
+            .unicast_address(IpAddr::V6(Ipv6Addr::LOCALHOST))
+            .build()
+            .unwrap();
+        assert_eq!(
+            origin.to_string(),
+            "o=bob 2890844526 2890842807 IN IP6 ::1\r\n"
+        );
+
+        let origin = Origin::builder()
+            .session_id("1")
+            .addrtype(AddrType::IP6)
+            .unicast_address("host.example.com")
+            .build()
+            .unwrap();
+        assert_eq!(origin.addrtype(), &AddrType::IP6);
+    }
+
+    #[test]
+    fn test_origin_builder_invalid() {
+        assert_eq!(
+            Origin::builder()
+                .session_id("1")
+                .addrtype(AddrType::IP6)
+                .build(),
+            Err(SdpError::AddressTypeMismatch)
+        );
+        for session_id in ["", "12a", "-1"] {
+            assert_eq!(
+                Origin::builder().session_id(session_id).build(),
+                Err(SdpError::InvalidSessionId)
+            );
+        }
+    }
+
+    #[test]
+    fn test_origin_try_new() {
+        let address = || ConnectionAddress::Ip(IpAddr::V4(Ipv4Addr::LOCALHOST));
+        let origin = Origin::try_new("-", "1", 1, NetType::IN, AddrType::IP4, address()).unwrap();
+        assert_eq!(origin.addrtype(), &AddrType::IP4);
+        assert_eq!(
+            Origin::try_new("-", "1", 1, NetType::IN, AddrType::IP6, address()),
+            Err(SdpError::AddressTypeMismatch)
+        );
+        let fqdn = ConnectionAddress::Fqdn("host.example.com");
+        assert!(Origin::try_new("-", "1", 1, NetType::IN, AddrType::IP6, fqdn).is_ok());
+    }
+
     #[test]
     fn test_parse_origin() {
         let (tail, value) =
@@ -238,10 +683,44 @@ mod tests {
         assert_eq!(value.addrtype, AddrType::IP4);
         assert_eq!(
             value.unicast_address,
-            IpAddr::V4(Ipv4Addr::new(192, 168, 10, 1))
+            ConnectionAddress::Ip(IpAddr::V4(Ipv4Addr::new(192, 168, 10, 1)))
+        );
+    }
+
+    #[test]
+    fn test_parse_origin_line_endings() {
+        for input in [
+            "o=jdoe 2890844526 2890842807 IN IP4 192.168.10.1\n",
+            "o=jdoe 2890844526 2890842807 IN IP4 192.168.10.1",
+        ] {
+            let (tail, value) = parse_origin::<()>(input).unwrap();
+            assert_eq!(tail, "");
+            assert_eq!(
+                value.unicast_address,
+                ConnectionAddress::Ip(IpAddr::V4(Ipv4Addr::new(192, 168, 10, 1)))
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_unicast_address_rejects_multicast_suffix() {
+        assert!(parse_unicast_address::<()>("224.2.1.1/127\r\n").is_err());
+    }
+
+    #[test]
+    fn test_parse_origin_invalid_address() {
+        assert!(
+            parse_origin::<()>("o=jdoe 2890844526 2890842807 IN IP4 224.2.1.1/127\r\n").is_err()
         );
     }
 
+    #[test]
+    fn test_origin_display() {
+        let input = "o=jdoe 2890844526 2890842807 IN IP4 192.168.10.1\r\n";
+        let (_, value) = parse_origin::<()>(input).unwrap();
+        assert_eq!(value.to_string(), input);
+    }
+
     #[test]
     fn test_parse_origin_with_ipv6() {
         let (tail, value) =
@@ -254,7 +733,17 @@ mod tests {
         assert_eq!(value.addrtype, AddrType::IP6);
         assert_eq!(
             value.unicast_address,
-            IpAddr::V6(Ipv6Addr::new(0, 0, 0, 0, 0, 0, 0, 1))
+            ConnectionAddress::Ip(IpAddr::V6(Ipv6Addr::new(0, 0, 0, 0, 0, 0, 0, 1)))
+        );
+    }
+
+    #[test]
+    fn test_parse_origin_with_fqdn() {
+        let (tail, value) = parse_origin::<()>("o=- 123 456 IN IP4 host.example.com\r\n").unwrap();
+        assert_eq!(tail, "");
+        assert_eq!(
+            value.unicast_address,
+            ConnectionAddress::Fqdn("host.example.com")
         );
     }
 }
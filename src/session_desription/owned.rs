@@ -0,0 +1,224 @@
+use std::fmt;
+
+use super::SessionDescription;
+use crate::error::SdpError;
+
+/// Copies a value of the model, with every string it borrows replaced through `strings`.
+pub(crate) trait Rebind {
+    type Output<'b>;
+
+    fn rebind<'b>(&self, strings: &mut dyn FnMut(&str) -> &'b str) -> Self::Output<'b>;
+}
+
+impl Rebind for &str {
+    type Output<'b> = &'b str;
+
+    fn rebind<'b>(&self, strings: &mut dyn FnMut(&str) -> &'b str) -> &'b str {
+        strings(self)
+    }
+}
+
+impl<T: Rebind> Rebind for Option<T> {
+    type Output<'b> = Option<T::Output<'b>>;
+
+    fn rebind<'b>(&self, strings: &mut dyn FnMut(&str) -> &'b str) -> Self::Output<'b> {
+        self.as_ref().map(|value| value.rebind(strings))
+    }
+}
+
+impl<T: Rebind> Rebind for Vec<T> {
+    type Output<'b> = Vec<T::Output<'b>>;
+
+    fn rebind<'b>(&self, strings: &mut dyn FnMut(&str) -> &'b str) -> Self::Output<'b> {
+        self.iter().map(|value| value.rebind(strings)).collect()
+    }
+}
+
+impl<A: Rebind, B: Rebind> Rebind for (A, B) {
+    type Output<'b> = (A::Output<'b>, B::Output<'b>);
+
+    fn rebind<'b>(&self, strings: &mut dyn FnMut(&str) -> &'b str) -> Self::Output<'b> {
+        (self.0.rebind(strings), self.1.rebind(strings))
+    }
+}
+
+/// A session description that owns its strings, so it can outlive the buffer it was parsed
+/// from.
+///
+/// [`SessionDescription`] borrows every string from its input. An `OwnedSessionDescription`
+/// holds a copy of every one of them instead, together with a [`SessionDescription`]
+/// borrowing from that copy, which [`OwnedSessionDescription::session_description`] hands
+/// out. Nothing is parsed again once the value is built.
+pub struct OwnedSessionDescription {
+    // Borrows from `text` and `_strings`. The `'static` lifetime is never handed out, see
+    // `session_description`, and the field is declared first so that it is dropped first.
+    description: SessionDescription<'static>,
+    text: Option<String>,
+    // Only kept alive for `description`.
+    _strings: Vec<String>,
+}
+
+impl OwnedSessionDescription {
+    /// Parses `text` and takes ownership of it, so the description borrows from it.
+    pub(crate) fn parse(text: String) -> Result<Self, SdpError> {
+        // SAFETY: the heap buffer of `text` is never modified nor freed while `description`
+        // lives, since both are stored in `Self` and `text` is never handed out mutably.
+        let input: &'static str = unsafe { &*(text.as_str() as *const str) };
+        let description = SessionDescription::from_str(input)?;
+        Ok(Self {
+            description,
+            text: Some(text),
+            _strings: Vec::new(),
+        })
+    }
+
+    fn copy(description: &SessionDescription<'_>, text: Option<String>) -> Self {
+        let mut strings = Vec::new();
+        let description = description.rebind(&mut |s| {
+            let string = s.to_owned();
+            // SAFETY: as in `parse`, the heap buffer of `string` is kept in `_strings` and
+            // never modified nor freed while `description` lives.
+            let s: &'static str = unsafe { &*(string.as_str() as *const str) };
+            strings.push(string);
+            s
+        });
+        Self {
+            description,
+            text,
+            _strings: strings,
+        }
+    }
+
+    /// Returns the session description, borrowing from `self`.
+    pub fn session_description(&self) -> &SessionDescription<'_> {
+        &self.description
+    }
+
+    /// Returns the text the description was parsed from, e.g. by
+    /// [`SdpStreamParser`](crate::SdpStreamParser), or `None` if it was copied with
+    /// [`SessionDescription::into_owned`].
+    pub fn as_str(&self) -> Option<&str> {
+        self.text.as_deref()
+    }
+}
+
+impl Clone for OwnedSessionDescription {
+    fn clone(&self) -> Self {
+        Self::copy(&self.description, self.text.clone())
+    }
+}
+
+impl fmt::Debug for OwnedSessionDescription {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("OwnedSessionDescription")
+            .field(self.session_description())
+            .finish()
+    }
+}
+
+impl PartialEq for OwnedSessionDescription {
+    fn eq(&self, other: &Self) -> bool {
+        self.session_description() == other.session_description()
+    }
+}
+
+impl Eq for OwnedSessionDescription {}
+
+impl fmt::Display for OwnedSessionDescription {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.session_description())
+    }
+}
+
+impl SessionDescription<'_> {
+    /// Copies the session description into an [`OwnedSessionDescription`], which does not
+    /// borrow the input.
+    pub fn into_owned(self) -> OwnedSessionDescription {
+        OwnedSessionDescription::copy(&self, None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::attribute::{Attribute, Group};
+    use crate::options::ParseOptions;
+    use crate::session_desription::SessionName;
+
+    #[test]
+    fn test_into_owned() {
+        let input = String::from("v=0\r\no=jdoe 2890844526 2890842807 IN IP4 192.168.10.1\r\ns=SDP Seminar\r\nt=0 0\r\nk=clear:secret\r\nx=vendor value\r\nm=audio 49170 RTP/AVP 0\r\na=rtpmap:0 PCMU/8000\r\n");
+        let options = ParseOptions {
+            keep_encryption_key: true,
+            ..ParseOptions::default()
+        };
+        let owned = SessionDescription::from_str_with_options(&input, &options)
+            .unwrap()
+            .into_owned();
+        let expected = input.clone();
+        drop(input);
+
+        let session = owned.session_description();
+        assert_eq!(session.session_name().name(), "SDP Seminar");
+        assert_eq!(
+            session.encryption_key().unwrap().to_string(),
+            "k=clear:secret\r\n"
+        );
+        assert_eq!(session.unknown_lines(), &[('x', "vendor value")]);
+        assert_eq!(session.media()[0].rtpmaps().count(), 1);
+        assert_eq!(owned.as_str(), None);
+        assert_eq!(owned.to_string(), expected);
+
+        let copy = owned.clone();
+        drop(owned);
+        assert_eq!(copy.to_string(), expected);
+    }
+
+    #[test]
+    fn test_into_owned_does_not_borrow_input() {
+        let input = "v=0\r\no=- 1 1 IN IP4 127.0.0.1\r\ns=Seminar\r\nt=0 0\r\na=tool:foo\r\n";
+        let range = input.as_bytes().as_ptr_range();
+        let owned = SessionDescription::from_str(input).unwrap().into_owned();
+        let session = owned.session_description();
+        assert!(!range.contains(&session.session_name().name().as_ptr()));
+        assert!(!range.contains(&session.attributes()[0].value().unwrap().as_ptr()));
+        assert_eq!(session.to_string(), input);
+    }
+
+    #[test]
+    fn test_into_owned_lenient() {
+        let input = "v=0\r\ns=-\r\no=- 1 1 IN IP4 127.0.0.1\r\n";
+        let session =
+            SessionDescription::from_str_with_options(input, &ParseOptions::lenient()).unwrap();
+        let owned = session.into_owned();
+        assert_eq!(
+            owned.to_string(),
+            "v=0\r\no=- 1 1 IN IP4 127.0.0.1\r\ns=-\r\nt=0 0\r\n"
+        );
+        assert_eq!(owned.session_description().origin().username(), "-");
+    }
+
+    #[test]
+    fn test_into_owned_modified() {
+        let input = "v=0\r\no=- 1 1 IN IP4 127.0.0.1\r\ns=-\r\nt=0 0\r\n";
+        let mut session = SessionDescription::from_str(input).unwrap();
+        session.session_name = SessionName::new("");
+        session
+            .attributes_mut()
+            .push(Attribute::Group(Group::new("BUNDLE", vec!["0"])));
+        let expected = session.clone();
+        let owned = session.into_owned();
+        assert_eq!(owned.session_description(), &expected);
+        assert_eq!(owned.session_description().session_name().name(), "");
+    }
+
+    #[test]
+    fn test_parse() {
+        let input = "v=0\no=- 1 1 IN IP4 127.0.0.1\ns=-\nt=0 0\n";
+        let owned = OwnedSessionDescription::parse(input.to_string()).unwrap();
+        assert_eq!(owned.as_str(), Some(input));
+        assert_eq!(owned.session_description().origin().username(), "-");
+        assert_eq!(owned.clone().as_str(), Some(input));
+        assert!(OwnedSessionDescription::parse("v=1\n".to_string()).is_err());
+    }
+}
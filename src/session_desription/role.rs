@@ -0,0 +1,109 @@
+use super::SessionDescription;
+use crate::attribute::{Attribute, Setup};
+
+/// The role a session description plays in an offer/answer exchange (RFC 3264).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Role {
+    Offer,
+    Answer,
+}
+
+/// Transport protocols WebRTC uses for its media (RFC 8829 §5.1.2) and data channels (RFC 8841).
+const WEBRTC_PROTOS: [&str; 4] = [
+    "UDP/TLS/RTP/SAVPF",
+    "TCP/TLS/RTP/SAVPF",
+    "UDP/DTLS/SCTP",
+    "TCP/DTLS/SCTP",
+];
+
+impl SessionDescription<'_> {
+    /// Returns `true` if the session looks like it was produced by a WebRTC endpoint: it has
+    /// at least one media section, every media section uses a DTLS-based WebRTC protocol
+    /// (e.g. `UDP/TLS/RTP/SAVPF`), and a DTLS fingerprint (`a=fingerprint:`) is given at
+    /// session level or on every media section.
+    pub fn is_webrtc(&self) -> bool {
+        !self.media.is_empty()
+            && self.media.iter().all(|media| {
+                WEBRTC_PROTOS.contains(&media.proto())
+                    && (self.fingerprint().is_some() || media.fingerprint().is_some())
+            })
+    }
+
+    /// Guesses whether the session is an offer or an answer from its `a=setup:` attributes.
+    ///
+    /// SDP does not carry its offer/answer role, so this is a heuristic: RFC 5763 requires
+    /// an offerer to use `actpass` and an answerer to pick `active` or `passive`. Any
+    /// `a=setup:actpass`, at session or media level, makes the session an offer; otherwise
+    /// any `a=setup:active` or `a=setup:passive` makes it an answer. Returns `None` if the
+    /// session has no `a=setup:` attribute, or only `holdconn`.
+    ///
+    /// Offers that force a role with `active` or `passive` are misreported as answers.
+    pub fn guess_role(&self) -> Option<Role> {
+        let mut role = None;
+        let setups = self
+            .attributes
+            .iter()
+            .chain(self.media.iter().flat_map(|media| media.attributes()));
+        for attribute in setups {
+            match attribute {
+                Attribute::Setup(Setup::ActPass) => return Some(Role::Offer),
+                Attribute::Setup(Setup::Active | Setup::Passive) => role = Some(Role::Answer),
+                _ => {}
+            }
+        }
+        role
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SESSION: &str = "v=0\r\no=- 1 1 IN IP4 127.0.0.1\r\ns=-\r\nt=0 0\r\n";
+
+    fn session_with(media: &str) -> String {
+        format!("{}{}", SESSION, media)
+    }
+
+    #[test]
+    fn test_is_webrtc() {
+        let input = session_with("a=fingerprint:sha-256 AB:CD\r\nm=audio 9 UDP/TLS/RTP/SAVPF 111\r\nm=application 9 UDP/DTLS/SCTP webrtc-datachannel\r\n");
+        assert!(SessionDescription::from_str(&input).unwrap().is_webrtc());
+
+        let input =
+            session_with("m=audio 9 UDP/TLS/RTP/SAVPF 111\r\na=fingerprint:sha-256 AB:CD\r\n");
+        assert!(SessionDescription::from_str(&input).unwrap().is_webrtc());
+    }
+
+    #[test]
+    fn test_is_not_webrtc() {
+        for media in [
+            "",
+            "m=audio 49170 RTP/AVP 0\r\n",
+            "m=audio 9 UDP/TLS/RTP/SAVPF 111\r\n",
+            "a=fingerprint:sha-256 AB:CD\r\nm=audio 9 UDP/TLS/RTP/SAVPF 111\r\nm=audio 49170 RTP/AVP 0\r\n",
+        ] {
+            let input = session_with(media);
+            assert!(!SessionDescription::from_str(&input).unwrap().is_webrtc(), "{}", media);
+        }
+    }
+
+    #[test]
+    fn test_guess_role() {
+        for (media, expected) in [
+            ("m=audio 9 UDP/TLS/RTP/SAVPF 111\r\na=setup:actpass\r\n", Some(Role::Offer)),
+            ("m=audio 9 UDP/TLS/RTP/SAVPF 111\r\na=setup:active\r\n", Some(Role::Answer)),
+            (
+                "m=audio 9 UDP/TLS/RTP/SAVPF 111\r\na=setup:passive\r\nm=video 9 UDP/TLS/RTP/SAVPF 96\r\na=setup:actpass\r\n",
+                Some(Role::Offer),
+            ),
+            ("a=setup:passive\r\nm=audio 9 UDP/TLS/RTP/SAVPF 111\r\n", Some(Role::Answer)),
+            ("m=audio 9 UDP/TLS/RTP/SAVPF 111\r\na=setup:holdconn\r\n", None),
+            ("m=audio 49170 RTP/AVP 0\r\n", None),
+        ] {
+            let input = session_with(media);
+            assert_eq!(SessionDescription::from_str(&input).unwrap().guess_role(), expected);
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use std::fmt;
+
 use nom::{
     bytes::complete::tag,
     character::complete::{line_ending, not_line_ending},
@@ -7,7 +9,10 @@ use nom::{
     IResult, Parser,
 };
 
-#[derive(Debug)]
+use super::Rebind;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SessionName<'a> {
     name: &'a str,
 }
@@ -16,12 +21,22 @@ impl<'a> SessionName<'a> {
     pub fn new(name: &'a str) -> Self {
         Self { name }
     }
+
+    pub fn name(&self) -> &'a str {
+        self.name
+    }
+
     /// Validates the session name against the given charset.
     ///
     /// - If a session-level "a=charset:" attribute is present,
     ///   it specifies the character set used in the "s=" field. If a session-level "a=charset:" attribute is not present,
     ///   the "s=" field MUST contain ISO 10646 characters in UTF-8 encoding.
     ///
+    /// An empty `char_set` stands for the UTF-8 default. Besides `UTF-8`, the `ISO-8859-1` and
+    /// `US-ASCII` charsets are known (case-insensitively); any other charset is rejected.
+    /// Control characters other than tab, and the U+FFFD replacement character left behind by
+    /// a lossy decode, are never valid.
+    ///
     /// # Arguments
     ///
     /// * `char_set` - A string slice that holds the charset to validate the session name against.
@@ -33,26 +48,53 @@ impl<'a> SessionName<'a> {
     /// # Example
     ///
     /// ```
+    /// use sdp_parser::SessionName;
+    ///
     /// let session_name = SessionName::new("Session Name");
     /// let result = session_name.validate_char_set("UTF-8");
     /// assert!(result);
     /// ```
     /// [1]: https://tools.ietf.org/html/rfc8866#section-5.3
     pub fn validate_char_set(&self, char_set: &str) -> bool {
-        if char_set.is_empty() {
-            return true;
+        // The name is a `&str` and so already UTF-8, what is left to check is that every
+        // character is printable and representable in the charset.
+        let Some(in_char_set) = char_set_filter(char_set) else {
+            return false;
+        };
+        self.name.chars().all(|c| {
+            (c == '\t' || !c.is_control()) && c != char::REPLACEMENT_CHARACTER && in_char_set(c)
+        })
+    }
+}
+
+/// Returns `true` if [`SessionName::validate_char_set`] knows how to validate `char_set`.
+pub(crate) fn is_supported_char_set(char_set: &str) -> bool {
+    char_set_filter(char_set).is_some()
+}
+
+/// Returns a predicate telling whether a character is representable in `char_set`.
+fn char_set_filter(char_set: &str) -> Option<fn(char) -> bool> {
+    match char_set.to_ascii_uppercase().as_str() {
+        "" | "UTF-8" => Some(|_| true),
+        "ISO-8859-1" => Some(|c| u32::from(c) <= 0xff),
+        "US-ASCII" => Some(|c| c.is_ascii()),
+        _ => None,
+    }
+}
+
+impl Rebind for SessionName<'_> {
+    type Output<'b> = SessionName<'b>;
+
+    fn rebind<'b>(&self, strings: &mut dyn FnMut(&str) -> &'b str) -> SessionName<'b> {
+        SessionName {
+            name: strings(self.name),
         }
-        // Validate the session name against the given charset.
-        // If the charset is not present, the session name MUST contain ISO 10646 characters in
-        // UTF-8 encoding.
-        // For now, we are just returning true. TODO: Implement charset validation.
-        return true;
     }
 }
 
-impl PartialEq for SessionName<'_> {
-    fn eq(&self, other: &Self) -> bool {
-        self.name == other.name
+impl fmt::Display for SessionName<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "s={}\r\n", self.name)
     }
 }
 
@@ -75,7 +117,7 @@ impl PartialEq for SessionName<'_> {
 ///
 /// # Example
 ///
-/// ```
+/// ```ignore
 /// let input = "s=Session Name\r\n";
 /// let result = parse_session_name(input);
 /// assert!(result.is_ok());
@@ -86,7 +128,7 @@ pub fn parse_session_name<'a, 'i: 'a, E: ParseError<&'i str>>(
 ) -> IResult<&'i str, SessionName<'i>, E> {
     map(
         preceded(tag("s="), terminated(not_line_ending, opt(line_ending))),
-        |s| SessionName::new(s),
+        SessionName::new,
     )
     .parse(input)
 }
@@ -103,6 +145,17 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn test_parse_session_name_line_endings() {
+        let (tail, result) = parse_session_name::<()>("s=Session Name\nt=0 0\n").unwrap();
+        assert_eq!(tail, "t=0 0\n");
+        assert_eq!(result, SessionName::new("Session Name"));
+
+        let (tail, result) = parse_session_name::<()>("s=Session Name").unwrap();
+        assert_eq!(tail, "");
+        assert_eq!(result, SessionName::new("Session Name"));
+    }
+
     #[test]
     fn test_default_session_name_with_space() {
         let input = "s= \r\n";
@@ -119,10 +172,35 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn test_session_name_display() {
+        assert_eq!(
+            SessionName::new("SDP Seminar").to_string(),
+            "s=SDP Seminar\r\n"
+        );
+    }
+
     #[test]
     fn test_validate_char_set() {
         let session_name = SessionName::new("Session Name");
         let result = session_name.validate_char_set("UTF-8");
         assert!(result);
     }
+
+    #[test]
+    fn test_validate_char_set_utf8() {
+        assert!(SessionName::new("Séance ☎").validate_char_set(""));
+        assert!(SessionName::new("Séance ☎").validate_char_set("utf-8"));
+        assert!(!SessionName::new("bad\u{fffd}name").validate_char_set(""));
+        assert!(!SessionName::new("bell\u{7}").validate_char_set(""));
+    }
+
+    #[test]
+    fn test_validate_char_set_other_charsets() {
+        assert!(SessionName::new("Séance").validate_char_set("ISO-8859-1"));
+        assert!(!SessionName::new("Séance ☎").validate_char_set("ISO-8859-1"));
+        assert!(SessionName::new("Seance").validate_char_set("US-ASCII"));
+        assert!(!SessionName::new("Séance").validate_char_set("US-ASCII"));
+        assert!(!SessionName::new("Seance").validate_char_set("KOI8-R"));
+    }
 }
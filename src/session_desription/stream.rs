@@ -0,0 +1,146 @@
+use super::{check_line, OwnedSessionDescription};
+use crate::error::SdpError;
+use crate::skip_preamble;
+
+/// Parses a session description that arrives in chunks, e.g. over a slow relay.
+///
+/// Chunks are accumulated with [`SdpStreamParser::feed`], which parses every line on its own
+/// as soon as it is complete, so that a malformed line is caught without waiting for the
+/// rest. [`SdpStreamParser::finish`] then parses the whole description once the last chunk
+/// has arrived, running the checks that span lines, such as their order and the required
+/// lines. A chunk may end anywhere, even in the middle of a line.
+///
+/// # Example
+///
+/// ```
+/// use sdp_parser::SdpStreamParser;
+///
+/// let mut parser = SdpStreamParser::new();
+/// for chunk in ["v=0\r\no=- 1 1 IN ", "IP4 10.0.0.1\r\ns=-\r", "\nt=0 0\r\n"] {
+///     parser.feed(chunk);
+/// }
+/// let session = parser.finish().unwrap();
+/// assert_eq!(session.session_description().origin().username(), "-");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct SdpStreamParser {
+    buffer: String,
+    /// Offset of the first line that is not complete yet.
+    checked: usize,
+    error: Option<SdpError>,
+}
+
+impl SdpStreamParser {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends `chunk` to the description and parses the lines it completes, each on its own.
+    ///
+    /// Once a line is found to be malformed, later chunks are ignored and
+    /// [`SdpStreamParser::finish`] returns the error.
+    pub fn feed(&mut self, chunk: &str) {
+        if self.error.is_some() {
+            return;
+        }
+        self.buffer.push_str(chunk);
+        while let Some(end) = self.buffer[self.checked..].find('\n') {
+            let mut line = self.buffer[self.checked..self.checked + end].trim_end_matches('\r');
+            if skip_preamble(&self.buffer[..self.checked]).is_empty() {
+                line = skip_preamble(line);
+            }
+            if !line.is_empty() {
+                if let Err(err) = check_line(&self.buffer, line) {
+                    self.error = Some(err);
+                    return;
+                }
+            }
+            self.checked += end + 1;
+        }
+    }
+
+    /// Parses the description fed so far, see [`crate::parse`]. The returned description
+    /// owns the text as it was fed.
+    pub fn finish(self) -> Result<OwnedSessionDescription, SdpError> {
+        if let Some(error) = self.error {
+            return Err(error);
+        }
+        OwnedSessionDescription::parse(self.buffer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "v=0\r\no=jdoe 2890844526 2890842807 IN IP4 10.47.16.5\r\ns=SDP Seminar\r\nc=IN IP4 224.2.17.12/127\r\nt=2873397496 2873404696\r\nm=audio 49170 RTP/AVP 0\r\na=rtpmap:0 PCMU/8000\r\nm=video 51372 RTP/AVP 99\r\na=rtpmap:99 h263-1998/90000\r\n";
+
+    #[test]
+    fn test_stream_parser() {
+        for size in [1, 2, 7, 64, INPUT.len()] {
+            let mut parser = SdpStreamParser::new();
+            for chunk in INPUT.as_bytes().chunks(size) {
+                parser.feed(std::str::from_utf8(chunk).unwrap());
+            }
+            let session = parser.finish().unwrap();
+            assert_eq!(session.as_str(), Some(INPUT), "chunks of {} bytes", size);
+        }
+    }
+
+    #[test]
+    fn test_stream_parser_malformed_line() {
+        let mut parser = SdpStreamParser::new();
+        parser.feed("v=0\r\no=- 1 1 IN IP4 10.0.0.1\r\nbogus");
+        parser.feed(" line\r\ns=-\r\n");
+        assert_eq!(
+            parser.finish().unwrap_err(),
+            SdpError::MalformedLine {
+                line_type: 'b',
+                offset: 30,
+                line: "bogus line".to_string(),
+                detail: "expected a `<type>=<value>` line".to_string(),
+                rfc_reference: "RFC 8866 §5",
+            }
+        );
+    }
+
+    #[test]
+    fn test_stream_parser_malformed_value() {
+        let mut parser = SdpStreamParser::new();
+        parser.feed("v=0\r\no=- 1 1 IN IP4 10.0.0.1\r\ns=-\r\nt=0 0 junk\r\n");
+        parser.feed("m=audio 9 RTP/AVP 0\r\n");
+        assert!(matches!(
+            parser.finish().unwrap_err(),
+            SdpError::MalformedLine { line_type: 't', offset: 40, line, .. } if line == "t=0 0 junk"
+        ));
+    }
+
+    #[test]
+    fn test_stream_parser_keeps_text() {
+        let input = "v=0\r\no=- 1 1 IN IP4 10.0.0.1\ns=-\nt=0 0\na=rtpmap:0 pcmu/08000\n";
+        let mut parser = SdpStreamParser::new();
+        parser.feed(input);
+        let session = parser.finish().unwrap();
+        assert_eq!(session.as_str(), Some(input));
+        assert_eq!(session.session_description().attributes().len(), 1);
+    }
+
+    #[test]
+    fn test_stream_parser_preamble() {
+        let mut parser = SdpStreamParser::new();
+        parser.feed("\u{feff}");
+        parser.feed(" \r\n\r\nv=0\r\no=- 1 1 IN IP4 10.0.0.1\r\ns=-\r\nt=0 0\r\n");
+        let session = parser.finish().unwrap();
+        assert_eq!(session.session_description().origin().username(), "-");
+    }
+
+    #[test]
+    fn test_stream_parser_incomplete_description() {
+        let mut parser = SdpStreamParser::new();
+        parser.feed("v=0\r\no=- 1 1 IN IP4 10.0.0.1\r\n");
+        assert_eq!(
+            parser.finish().unwrap_err(),
+            SdpError::MissingRequiredLine("s=")
+        );
+    }
+}
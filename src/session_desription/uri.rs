@@ -0,0 +1,69 @@
+use std::fmt;
+
+use nom::{
+    bytes::complete::tag,
+    character::complete::{line_ending, not_line_ending},
+    combinator::{map, opt},
+    error::ParseError,
+    sequence::{preceded, terminated},
+    IResult, Parser,
+};
+
+use super::Rebind;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Uri<'a> {
+    uri: &'a str,
+}
+
+impl<'a> Uri<'a> {
+    pub fn new(uri: &'a str) -> Self {
+        Self { uri }
+    }
+
+    pub fn uri(&self) -> &'a str {
+        self.uri
+    }
+}
+
+impl Rebind for Uri<'_> {
+    type Output<'b> = Uri<'b>;
+
+    fn rebind<'b>(&self, strings: &mut dyn FnMut(&str) -> &'b str) -> Uri<'b> {
+        Uri {
+            uri: strings(self.uri),
+        }
+    }
+}
+
+impl fmt::Display for Uri<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "u={}\r\n", self.uri)
+    }
+}
+
+/// u=<uri>
+/// u=http://www.example.com/seminars/sdp.pdf
+/// see https://tools.ietf.org/html/rfc8866#section-5.5
+pub fn parse_uri<'i, E: ParseError<&'i str>>(input: &'i str) -> IResult<&'i str, Uri<'i>, E> {
+    map(
+        preceded(tag("u="), terminated(not_line_ending, opt(line_ending))),
+        Uri::new,
+    )
+    .parse(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_uri() {
+        let input = "u=http://www.example.com/seminars/sdp.pdf\r\n";
+        let (tail, result) = parse_uri::<()>(input).unwrap();
+        assert_eq!(tail, "");
+        assert_eq!(result.uri(), "http://www.example.com/seminars/sdp.pdf");
+        assert_eq!(result.to_string(), input);
+    }
+}
@@ -0,0 +1,63 @@
+use std::fmt;
+
+use super::Rebind;
+
+/// The lines of a section other than its `a=` and unknown lines, as they were parsed along
+/// with their line endings, see
+/// [`ParseOptions::preserve_attribute_lines`](crate::ParseOptions::preserve_attribute_lines).
+///
+/// Empty unless the lines were kept. Like the lines kept by
+/// [`AttributeList`](crate::AttributeList), they are ignored by comparisons.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) struct VerbatimLines<'a> {
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    lines: Vec<&'a str>,
+}
+
+impl<'a> VerbatimLines<'a> {
+    pub(crate) fn push(&mut self, line: &'a str) {
+        self.lines.push(line);
+    }
+
+    /// Drops the kept lines, so that the section is serialized from its typed lines.
+    pub(crate) fn clear(&mut self) {
+        self.lines.clear();
+    }
+
+    /// Writes the kept lines, returning `false` if there are none.
+    pub(crate) fn write(&self, f: &mut fmt::Formatter<'_>) -> Result<bool, fmt::Error> {
+        for line in &self.lines {
+            write_line(f, line)?;
+        }
+        Ok(!self.lines.is_empty())
+    }
+}
+
+/// Writes a kept line, ending it with `\r\n` if it was the last line of an input without a
+/// final line ending.
+pub(crate) fn write_line(f: &mut fmt::Formatter<'_>, line: &str) -> fmt::Result {
+    f.write_str(line)?;
+    if !line.ends_with('\n') {
+        f.write_str("\r\n")?;
+    }
+    Ok(())
+}
+
+impl PartialEq for VerbatimLines<'_> {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for VerbatimLines<'_> {}
+
+impl Rebind for VerbatimLines<'_> {
+    type Output<'b> = VerbatimLines<'b>;
+
+    fn rebind<'b>(&self, strings: &mut dyn FnMut(&str) -> &'b str) -> VerbatimLines<'b> {
+        VerbatimLines {
+            lines: self.lines.rebind(strings),
+        }
+    }
+}
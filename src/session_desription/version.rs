@@ -1,4 +1,4 @@
-use std::str::FromStr;
+use std::{fmt, str::FromStr};
 
 use nom::{
     bytes::complete::tag,
@@ -9,7 +9,8 @@ use nom::{
     IResult, Parser,
 };
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Version {
     version: u8,
 }
@@ -18,6 +19,10 @@ impl Version {
     pub fn new(version: u8) -> Self {
         Self { version }
     }
+
+    pub fn version(&self) -> u8 {
+        self.version
+    }
 }
 
 #[derive(Debug)]
@@ -34,9 +39,9 @@ impl FromStr for Version {
     }
 }
 
-impl PartialEq for Version {
-    fn eq(&self, other: &Self) -> bool {
-        self.version == other.version
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "v={}\r\n", self.version)
     }
 }
 
@@ -75,6 +80,35 @@ mod tests {
         assert_eq!(result.1.version, expected.version);
     }
 
+    #[test]
+    fn test_parse_version_lf_line_ending() {
+        let (tail, result) = parse_version::<()>("v=0\ns=-\n").unwrap();
+        assert_eq!(tail, "s=-\n");
+        assert_eq!(result.version, 0);
+    }
+
+    #[test]
+    fn test_version_hash() {
+        use std::collections::HashSet;
+
+        let set: HashSet<_> = [Version::new(0), Version::new(0), Version::new(1)]
+            .into_iter()
+            .collect();
+        assert_eq!(set.len(), 2);
+    }
+
+    #[test]
+    fn test_version_ord() {
+        assert!(Version::new(0) < Version::new(1));
+        let versions = [Version::new(1), Version::new(0), Version::new(2)];
+        assert_eq!(versions.iter().max(), Some(&Version::new(2)));
+    }
+
+    #[test]
+    fn test_version_display() {
+        assert_eq!(Version::new(0).to_string(), "v=0\r\n");
+    }
+
     #[test]
     fn test_version_from_str_ok() {
         let input = "v=0";
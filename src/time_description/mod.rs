@@ -0,0 +1,3 @@
+mod time;
+pub(crate) use time::{parse_repeat_times, parse_time, parse_time_zones};
+pub use time::{RepeatTimes, TimeDescription, TimeZone};
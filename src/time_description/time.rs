@@ -0,0 +1,320 @@
+use std::fmt;
+
+use nom::{
+    bytes::complete::tag,
+    character::complete::{char, line_ending, one_of, space1, u64},
+    combinator::{map, map_opt, opt},
+    error::ParseError,
+    multi::{many1, separated_list1},
+    sequence::{preceded, separated_pair, terminated, tuple},
+    IResult, Parser,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TimeDescription {
+    start_time: u64,
+    stop_time: u64,
+    repeat_times: Vec<RepeatTimes>,
+}
+
+impl TimeDescription {
+    pub fn new(start_time: u64, stop_time: u64) -> Self {
+        Self {
+            start_time,
+            stop_time,
+            repeat_times: Vec::new(),
+        }
+    }
+
+    /// Returns the start time as a decimal NTP timestamp, `0` if unbounded.
+    pub fn start_time(&self) -> u64 {
+        self.start_time
+    }
+
+    /// Returns the stop time as a decimal NTP timestamp, `0` if unbounded.
+    pub fn stop_time(&self) -> u64 {
+        self.stop_time
+    }
+
+    /// Returns `true` for `t=0 0`, i.e. a session that is regarded as permanent.
+    pub fn is_permanent(&self) -> bool {
+        self.start_time == 0 && self.stop_time == 0
+    }
+
+    /// Returns the repeat times (`r=`) that follow the `t=` line, in order.
+    pub fn repeat_times(&self) -> &[RepeatTimes] {
+        &self.repeat_times
+    }
+
+    pub(crate) fn push_repeat_times(&mut self, repeat_times: RepeatTimes) {
+        self.repeat_times.push(repeat_times);
+    }
+}
+
+impl fmt::Display for TimeDescription {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "t={} {}\r\n", self.start_time, self.stop_time)?;
+        for repeat_times in &self.repeat_times {
+            write!(f, "{}", repeat_times)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RepeatTimes {
+    repeat_interval: u64,
+    active_duration: u64,
+    offsets: Vec<u64>,
+}
+
+impl RepeatTimes {
+    pub fn new(repeat_interval: u64, active_duration: u64, offsets: Vec<u64>) -> Self {
+        Self {
+            repeat_interval,
+            active_duration,
+            offsets,
+        }
+    }
+
+    /// Returns the repeat interval, in seconds.
+    pub fn repeat_interval(&self) -> u64 {
+        self.repeat_interval
+    }
+
+    /// Returns the active duration, in seconds.
+    pub fn active_duration(&self) -> u64 {
+        self.active_duration
+    }
+
+    /// Returns the offsets from the start time, in seconds.
+    pub fn offsets(&self) -> &[u64] {
+        &self.offsets
+    }
+}
+
+impl fmt::Display for RepeatTimes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "r={} {}", self.repeat_interval, self.active_duration)?;
+        for offset in &self.offsets {
+            write!(f, " {}", offset)?;
+        }
+        write!(f, "\r\n")
+    }
+}
+
+/// A time zone adjustment of a `z=` line: from `adjustment_time` on, the times of a repeated
+/// session are shifted by `offset`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TimeZone {
+    adjustment_time: u64,
+    offset: i64,
+}
+
+impl TimeZone {
+    pub fn new(adjustment_time: u64, offset: i64) -> Self {
+        Self {
+            adjustment_time,
+            offset,
+        }
+    }
+
+    /// Returns the time the adjustment takes effect, as a decimal NTP timestamp.
+    pub fn adjustment_time(&self) -> u64 {
+        self.adjustment_time
+    }
+
+    /// Returns the offset applied from the adjustment time on, in seconds.
+    pub fn offset(&self) -> i64 {
+        self.offset
+    }
+}
+
+impl fmt::Display for TimeZone {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.adjustment_time, self.offset)
+    }
+}
+
+/// Parses a typed time into seconds, i.e. a number optionally suffixed with `d` (days),
+/// `h` (hours), `m` (minutes) or `s` (seconds), and preceded by a `-` for the negative
+/// offsets of `z=` lines.
+/// see https://tools.ietf.org/html/rfc8866#section-9
+pub(crate) fn parse_typed_time<'i, E: ParseError<&'i str>>(
+    input: &'i str,
+) -> IResult<&'i str, i64, E> {
+    map_opt(
+        tuple((opt(char('-')), u64, opt(one_of("dhms")))),
+        |(sign, value, unit)| {
+            let multiplier = match unit {
+                Some('d') => 86400,
+                Some('h') => 3600,
+                Some('m') => 60,
+                _ => 1,
+            };
+            let seconds = i64::try_from(value).ok()?.checked_mul(multiplier)?;
+            Some(if sign.is_some() { -seconds } else { seconds })
+        },
+    )
+    .parse(input)
+}
+
+/// Parses a typed time that cannot be negative, as in `r=` lines.
+fn parse_unsigned_typed_time<'i, E: ParseError<&'i str>>(
+    input: &'i str,
+) -> IResult<&'i str, u64, E> {
+    map_opt(parse_typed_time, |seconds| u64::try_from(seconds).ok()).parse(input)
+}
+
+/// t=<start-time> <stop-time>
+/// t=3034423619 3042462419
+/// see https://tools.ietf.org/html/rfc8866#section-5.9
+pub fn parse_time<'i, E: ParseError<&'i str>>(
+    input: &'i str,
+) -> IResult<&'i str, TimeDescription, E> {
+    let (tail, _) = tag("t=").parse(input)?;
+    let (tail, (start_time, stop_time)) =
+        terminated(separated_pair(u64, space1, u64), opt(line_ending)).parse(tail)?;
+    Ok((tail, TimeDescription::new(start_time, stop_time)))
+}
+
+/// r=<repeat interval> <active duration> <offsets from start-time>
+/// r=7d 1h 0 25h
+/// see https://tools.ietf.org/html/rfc8866#section-5.10
+pub fn parse_repeat_times<'i, E: ParseError<&'i str>>(
+    input: &'i str,
+) -> IResult<&'i str, RepeatTimes, E> {
+    let (tail, _) = tag("r=").parse(input)?;
+    let (tail, (repeat_interval, active_duration)) =
+        separated_pair(parse_unsigned_typed_time, space1, parse_unsigned_typed_time).parse(tail)?;
+    let (tail, offsets) = terminated(
+        many1(preceded(space1, parse_unsigned_typed_time)),
+        opt(line_ending),
+    )
+    .parse(tail)?;
+    Ok((
+        tail,
+        RepeatTimes::new(repeat_interval, active_duration, offsets),
+    ))
+}
+
+/// z=<adjustment time> <offset> <adjustment time> <offset> ....
+/// z=2882844526 -1h 2898848070 0
+/// see https://tools.ietf.org/html/rfc8866#section-5.11
+pub fn parse_time_zones<'i, E: ParseError<&'i str>>(
+    input: &'i str,
+) -> IResult<&'i str, Vec<TimeZone>, E> {
+    let (tail, _) = tag("z=").parse(input)?;
+    let time_zone = map(
+        separated_pair(u64, space1, parse_typed_time),
+        |(adjustment_time, offset)| TimeZone::new(adjustment_time, offset),
+    );
+    terminated(separated_list1(space1, time_zone), opt(line_ending)).parse(tail)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_time() {
+        let (tail, value) = parse_time::<()>("t=3034423619 3042462419\r\n").unwrap();
+        assert_eq!(tail, "");
+        assert_eq!(value.start_time, 3034423619);
+        assert_eq!(value.stop_time, 3042462419);
+        assert!(!value.is_permanent());
+    }
+
+    #[test]
+    fn test_parse_time_permanent() {
+        let (tail, value) = parse_time::<()>("t=0 0\r\n").unwrap();
+        assert_eq!(tail, "");
+        assert_eq!(value, TimeDescription::new(0, 0));
+        assert!(value.is_permanent());
+    }
+
+    #[test]
+    fn test_time_display() {
+        assert_eq!(
+            TimeDescription::new(3034423619, 3042462419).to_string(),
+            "t=3034423619 3042462419\r\n"
+        );
+    }
+
+    #[test]
+    fn test_parse_typed_time() {
+        assert_eq!(parse_typed_time::<()>("7d").unwrap().1, 604800);
+        assert_eq!(parse_typed_time::<()>("25h").unwrap().1, 90000);
+        assert_eq!(parse_typed_time::<()>("30m").unwrap().1, 1800);
+        assert_eq!(parse_typed_time::<()>("10s").unwrap().1, 10);
+        assert_eq!(parse_typed_time::<()>("3600").unwrap().1, 3600);
+        assert_eq!(parse_typed_time::<()>("0").unwrap().1, 0);
+    }
+
+    #[test]
+    fn test_parse_typed_time_negative() {
+        assert_eq!(parse_typed_time::<()>("-1h").unwrap().1, -3600);
+        assert_eq!(parse_typed_time::<()>("-25").unwrap().1, -25);
+    }
+
+    #[test]
+    fn test_parse_typed_time_invalid() {
+        assert!(parse_typed_time::<()>("h").is_err());
+        assert!(parse_typed_time::<()>("+1h").is_err());
+        assert!(parse_typed_time::<()>("9223372036854775807d").is_err());
+        let (tail, _) = parse_typed_time::<()>("1w").unwrap();
+        assert_eq!(tail, "w");
+    }
+
+    #[test]
+    fn test_parse_repeat_times_negative() {
+        assert!(parse_repeat_times::<()>("r=7d 1h -1h\r\n").is_err());
+    }
+
+    #[test]
+    fn test_parse_repeat_times() {
+        let (tail, value) = parse_repeat_times::<()>("r=604800 3600 0 90000\r\n").unwrap();
+        assert_eq!(tail, "");
+        assert_eq!(value, RepeatTimes::new(604800, 3600, vec![0, 90000]));
+    }
+
+    #[test]
+    fn test_parse_repeat_times_compact() {
+        let (tail, value) = parse_repeat_times::<()>("r=7d 1h 0 25h\r\n").unwrap();
+        assert_eq!(tail, "");
+        assert_eq!(value.repeat_interval(), 604800);
+        assert_eq!(value.active_duration(), 3600);
+        assert_eq!(value.offsets(), &[0, 90000]);
+        assert_eq!(value.to_string(), "r=604800 3600 0 90000\r\n");
+    }
+
+    #[test]
+    fn test_parse_repeat_times_without_offsets() {
+        assert!(parse_repeat_times::<()>("r=7d 1h\r\n").is_err());
+    }
+
+    #[test]
+    fn test_parse_time_zones() {
+        let (tail, value) = parse_time_zones::<()>("z=2882844526 -1h 2898848070 0\r\n").unwrap();
+        assert_eq!(tail, "");
+        assert_eq!(
+            value,
+            [
+                TimeZone::new(2882844526, -3600),
+                TimeZone::new(2898848070, 0)
+            ]
+        );
+        assert_eq!(value[0].to_string(), "2882844526 -3600");
+
+        assert!(parse_time_zones::<()>("z=2882844526\r\n").is_err());
+        assert!(parse_time_zones::<()>("z=\r\n").is_err());
+    }
+
+    #[test]
+    fn test_parse_time_missing_stop_time() {
+        assert!(parse_time::<()>("t=3034423619\r\n").is_err());
+    }
+}